@@ -6,6 +6,9 @@ use std::ffi::{CStr, CString};
 use std::os::raw::c_char;
 use std::path::PathBuf;
 use std::str;
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
 
 use libgpiod::{Error, OperationType, Result, line::Offset};
 
@@ -105,9 +108,13 @@ impl Drop for SimDev {
     }
 }
 
-/// Sim Bank
+/// A single `/dev/gpiochipN` exposed by a simulated device.
+///
+/// A [`Sim`] always has at least one bank (the one [`Sim::new`] creates), and
+/// can be given more with [`Sim::add_bank`] before it is enabled - gpiosim
+/// lets a device attach several banks, each showing up as its own gpiochip.
 #[derive(Debug)]
-struct SimBank {
+pub struct SimBank {
     bank: *mut gpiosim_bank,
 }
 
@@ -130,7 +137,7 @@ impl SimBank {
         Ok(Self { bank })
     }
 
-    fn chip_name(&self) -> Result<&str> {
+    pub fn chip_name(&self) -> Result<&str> {
         // SAFETY: The string returned by gpiosim is guaranteed to live as long
         // as the `struct SimBank`.
         let name = unsafe { gpiosim_bank_get_chip_name(self.bank) };
@@ -141,7 +148,7 @@ impl SimBank {
             .map_err(Error::StringNotUtf8)
     }
 
-    fn dev_path(&self) -> Result<PathBuf> {
+    pub fn dev_path(&self) -> Result<PathBuf> {
         // SAFETY: The string returned by gpiosim is guaranteed to live as long
         // as the `struct SimBank`.
         let path = unsafe { gpiosim_bank_get_dev_path(self.bank) };
@@ -154,7 +161,7 @@ impl SimBank {
         Ok(PathBuf::from(path))
     }
 
-    fn val(&self, offset: Offset) -> Result<Value> {
+    pub fn val(&self, offset: Offset) -> Result<Value> {
         // SAFETY: `gpiosim_bank` is guaranteed to be valid here.
         let ret = unsafe { gpiosim_bank_get_value(self.bank, offset) };
 
@@ -168,7 +175,7 @@ impl SimBank {
         }
     }
 
-    fn set_label(&self, label: &str) -> Result<()> {
+    pub fn set_label(&self, label: &str) -> Result<()> {
         let label = CString::new(label).map_err(|_| Error::InvalidString)?;
 
         // SAFETY: `gpiosim_bank` is guaranteed to be valid here.
@@ -184,7 +191,7 @@ impl SimBank {
         }
     }
 
-    fn set_num_lines(&self, num: usize) -> Result<()> {
+    pub fn set_num_lines(&self, num: usize) -> Result<()> {
         // SAFETY: `gpiosim_bank` is guaranteed to be valid here.
         let ret = unsafe { gpiosim_bank_set_num_lines(self.bank, num) };
         if ret == -1 {
@@ -197,7 +204,7 @@ impl SimBank {
         }
     }
 
-    fn set_line_name(&self, offset: Offset, name: &str) -> Result<()> {
+    pub fn set_line_name(&self, offset: Offset, name: &str) -> Result<()> {
         let name = CString::new(name).map_err(|_| Error::InvalidString)?;
 
         // SAFETY: `gpiosim_bank` is guaranteed to be valid here.
@@ -215,7 +222,7 @@ impl SimBank {
         }
     }
 
-    fn set_pull(&self, offset: Offset, pull: Pull) -> Result<()> {
+    pub fn set_pull(&self, offset: Offset, pull: Pull) -> Result<()> {
         // SAFETY: `gpiosim_bank` is guaranteed to be valid here.
         let ret = unsafe { gpiosim_bank_set_pull(self.bank, offset, pull.val()) };
 
@@ -229,7 +236,7 @@ impl SimBank {
         }
     }
 
-    fn hog_line(&self, offset: Offset, name: &str, dir: Direction) -> Result<()> {
+    pub fn hog_line(&self, offset: Offset, name: &str, dir: Direction) -> Result<()> {
         let name = CString::new(name).map_err(|_| Error::InvalidString)?;
 
         // SAFETY: `gpiosim_bank` is guaranteed to be valid here.
@@ -260,7 +267,7 @@ impl Drop for SimBank {
 pub struct Sim {
     _ctx: SimCtx,
     dev: SimDev,
-    bank: SimBank,
+    banks: Vec<SimBank>,
 }
 
 impl Sim {
@@ -284,40 +291,58 @@ impl Sim {
         Ok(Self {
             _ctx: ctx,
             dev,
-            bank,
+            banks: vec![bank],
         })
     }
 
+    /// Attach another bank to this device, returning a handle to configure it.
+    ///
+    /// Each bank surfaces as its own `/dev/gpiochipN` once the device is
+    /// enabled. Must be called before [`Sim::enable`] - gpiosim only allows
+    /// banks to be added to a device while it is still disabled.
+    pub fn add_bank(&mut self) -> Result<&SimBank> {
+        let bank = SimBank::new(&self.dev)?;
+        self.banks.push(bank);
+
+        Ok(self.banks.last().unwrap())
+    }
+
+    /// All banks currently attached to this device, in creation order -
+    /// `banks()[0]` is the one [`Sim::new`] itself creates.
+    pub fn banks(&self) -> &[SimBank] {
+        &self.banks
+    }
+
     pub fn chip_name(&self) -> &str {
-        self.bank.chip_name().unwrap()
+        self.banks[0].chip_name().unwrap()
     }
 
     pub fn dev_path(&self) -> PathBuf {
-        self.bank.dev_path().unwrap()
+        self.banks[0].dev_path().unwrap()
     }
 
     pub fn val(&self, offset: Offset) -> Result<Value> {
-        self.bank.val(offset)
+        self.banks[0].val(offset)
     }
 
     pub fn set_label(&self, label: &str) -> Result<()> {
-        self.bank.set_label(label)
+        self.banks[0].set_label(label)
     }
 
     pub fn set_num_lines(&self, num: usize) -> Result<()> {
-        self.bank.set_num_lines(num)
+        self.banks[0].set_num_lines(num)
     }
 
     pub fn set_line_name(&self, offset: Offset, name: &str) -> Result<()> {
-        self.bank.set_line_name(offset, name)
+        self.banks[0].set_line_name(offset, name)
     }
 
     pub fn set_pull(&self, offset: Offset, pull: Pull) -> Result<()> {
-        self.bank.set_pull(offset, pull)
+        self.banks[0].set_pull(offset, pull)
     }
 
     pub fn hog_line(&self, offset: Offset, name: &str, dir: Direction) -> Result<()> {
-        self.bank.hog_line(offset, name, dir)
+        self.banks[0].hog_line(offset, name, dir)
     }
 
     pub fn enable(&self) -> Result<()> {
@@ -327,6 +352,47 @@ impl Sim {
     pub fn disable(&self) -> Result<()> {
         self.dev.disable()
     }
+
+    /// Spawn a background thread that walks `sequence`, sleeping for each
+    /// step's `Duration` and then setting `offset`'s pull to the paired
+    /// `Pull`.
+    ///
+    /// Replaces the sleep-then-`set_pull` calls tests would otherwise spread
+    /// across their own thread, with deterministic, known inter-event timing
+    /// baked into `sequence` - useful for asserting on `line_seqno`, debounce
+    /// behavior, or `EdgeKind` ordering in the edge-event path. `sim` is
+    /// taken as an `Arc<Mutex<..>>` since it is shared with the calling test
+    /// thread, which keeps driving the request in parallel.
+    pub fn drive_sequence(
+        sim: Arc<Mutex<Self>>,
+        offset: Offset,
+        sequence: Vec<(Pull, Duration)>,
+    ) -> JoinHandle<()> {
+        thread::spawn(move || {
+            for (pull, delay) in sequence {
+                thread::sleep(delay);
+                sim.lock().unwrap().set_pull(offset, pull).unwrap();
+            }
+        })
+    }
+
+    /// Like [`Sim::drive_sequence`], but loops `waveform` `repeat` times
+    /// instead of walking it once.
+    pub fn drive_pattern(
+        sim: Arc<Mutex<Self>>,
+        offset: Offset,
+        waveform: Vec<(Pull, Duration)>,
+        repeat: usize,
+    ) -> JoinHandle<()> {
+        thread::spawn(move || {
+            for _ in 0..repeat {
+                for &(pull, delay) in &waveform {
+                    thread::sleep(delay);
+                    sim.lock().unwrap().set_pull(offset, pull).unwrap();
+                }
+            }
+        })
+    }
 }
 
 impl Drop for Sim {
@@ -334,3 +400,74 @@ impl Drop for Sim {
         self.disable().unwrap()
     }
 }
+
+/// Builder for assembling a [`Sim`] with its pre-enable setup applied
+/// atomically.
+///
+/// `set_num_lines`, `set_line_name`, `set_pull` and `hog_line` are only
+/// meaningful before the simulated device is enabled, but [`Sim`] exposes
+/// them as loose methods on an already-enabled instance, which makes it easy
+/// to call one too late (or forget it). `SimBuilder` instead accumulates the
+/// whole fixture as one chained expression and applies it in `build()`, which
+/// creates the device, runs every `set_*` call, and enables it in one go.
+#[derive(Debug, Default)]
+pub struct SimBuilder {
+    num_lines: Option<usize>,
+    label: Option<String>,
+    line_names: Vec<(Offset, String)>,
+    pulls: Vec<(Offset, Pull)>,
+    hogs: Vec<(Offset, String, Direction)>,
+}
+
+impl SimBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_num_lines(&mut self, num: usize) -> &mut Self {
+        self.num_lines = Some(num);
+        self
+    }
+
+    pub fn set_label(&mut self, label: &str) -> &mut Self {
+        self.label = Some(label.to_owned());
+        self
+    }
+
+    pub fn set_line_name(&mut self, offset: Offset, name: &str) -> &mut Self {
+        self.line_names.push((offset, name.to_owned()));
+        self
+    }
+
+    pub fn set_pull(&mut self, offset: Offset, pull: Pull) -> &mut Self {
+        self.pulls.push((offset, pull));
+        self
+    }
+
+    pub fn hog_line(&mut self, offset: Offset, name: &str, dir: Direction) -> &mut Self {
+        self.hogs.push((offset, name.to_owned(), dir));
+        self
+    }
+
+    /// Create the device, apply every accumulated setting to its first bank,
+    /// and enable it.
+    pub fn build(&self) -> Result<Sim> {
+        let sim = Sim::new(self.num_lines, self.label.as_deref(), false)?;
+
+        for (offset, name) in &self.line_names {
+            sim.set_line_name(*offset, name)?;
+        }
+
+        for (offset, pull) in &self.pulls {
+            sim.set_pull(*offset, *pull)?;
+        }
+
+        for (offset, name, dir) in &self.hogs {
+            sim.hog_line(*offset, name, *dir)?;
+        }
+
+        sim.enable()?;
+
+        Ok(sim)
+    }
+}
@@ -3,7 +3,7 @@
 //
 // Minimal example of finding a line with the given name.
 
-use libgpiod::{gpiochip_devices, Result};
+use libgpiod::{find_line, Result};
 
 fn main() -> Result<()> {
     // Example configuration - customize to suit your situation
@@ -11,16 +11,13 @@ fn main() -> Result<()> {
 
     // Names are not guaranteed unique, so this finds the first line with
     // the given name.
-    for chip in gpiochip_devices(&"/dev")? {
-        let offset = chip.line_offset_from_name(line_name);
-
-        if offset.is_ok() {
+    match find_line(&"/dev", line_name) {
+        Ok((chip, offset)) => {
             let info = chip.info()?;
-            println!("{}: {} {}", line_name, info.name()?, offset?);
-            return Ok(());
+            println!("{}: {} {}", line_name, info.name()?, offset);
         }
+        Err(_) => println!("line '{line_name}' not found"),
     }
 
-    println!("line '{line_name}' not found");
     Ok(())
 }
@@ -33,21 +33,32 @@ fn main() -> Result<()> {
     let path = format!("/dev/gpiochip{}", args[1]);
     let chip = Chip::open(&path)?;
 
-    for &offset in offsets.iter() {
-        let _info = chip.watch_line_info(offset).unwrap();
-    }
+    // Keep the guards alive for the rest of `main` so their watches stay
+    // active while we wait for events below, but still get unwatched
+    // automatically if we return early instead of looping forever.
+    let _watches = offsets
+        .iter()
+        .map(|&offset| chip.watch_line_info_guarded(offset).unwrap())
+        .collect::<Vec<_>>();
 
     loop {
-        let event = chip.read_info_event().unwrap();
-        println!(
-            "event: {}, line: {}, timestamp: {:?}",
-            match event.event_type()? {
-                InfoChangeKind::LineRequested => "Line requested",
-                InfoChangeKind::LineReleased => "Line released",
-                InfoChangeKind::LineConfigChanged => "Line config changed",
-            },
-            event.line_info().unwrap().offset(),
-            event.timestamp()
-        );
+        // Block until at least one event is queued, then drain whatever
+        // burst of line transitions arrived alongside it in one go instead
+        // of re-entering a blocking read per line.
+        chip.wait_info_event(None)?;
+
+        for event in chip.read_info_events(16)? {
+            let event = event.unwrap();
+            println!(
+                "event: {}, line: {}, timestamp: {:?}",
+                match event.event_type()? {
+                    InfoChangeKind::LineRequested => "Line requested",
+                    InfoChangeKind::LineReleased => "Line released",
+                    InfoChangeKind::LineConfigChanged => "Line config changed",
+                },
+                event.line_info().unwrap().offset(),
+                event.timestamp()
+            );
+        }
     }
 }
@@ -5,10 +5,13 @@
 pub mod info {
     /// GPIO chip info event related definitions.
     pub use crate::info_event::*;
+    #[cfg(feature = "tokio")]
+    pub use crate::info_event_stream::*;
 }
 
 use std::cmp::Ordering;
 use std::ffi::{CStr, CString};
+use std::ops::Deref;
 use std::os::{raw::c_char, unix::prelude::AsRawFd};
 use std::path::Path;
 use std::ptr;
@@ -110,6 +113,20 @@ impl Chip {
         line::Info::new(info)
     }
 
+    /// Get a snapshot of every line exposed by the chip, offset 0 through
+    /// `num_lines() - 1`.
+    ///
+    /// A convenience over calling [`line_info`](Chip::line_info) in a loop -
+    /// libgpiod has no batched retrieval ioctl, so this still costs one
+    /// syscall per line, but it saves every caller building a full chip view
+    /// (e.g. for a status table on a large expander) from re-deriving the
+    /// `0..num_lines()` loop and its error handling.
+    pub fn all_line_info(&self) -> Result<Vec<line::Info>> {
+        (0..self.info()?.num_lines() as Offset)
+            .map(|offset| self.line_info(offset))
+            .collect()
+    }
+
     /// Get the current snapshot of information about the line at given offset and start watching
     /// it for future changes.
     pub fn watch_line_info(&self, offset: Offset) -> Result<line::Info> {
@@ -134,6 +151,14 @@ impl Chip {
         }
     }
 
+    /// Like [`watch_line_info`](Chip::watch_line_info), but returns a
+    /// [`LineInfoWatch`] guard that calls [`unwatch`](Chip::unwatch)
+    /// automatically when dropped, instead of leaving the caller to pair the
+    /// two calls up by hand.
+    pub fn watch_line_info_guarded(&self, offset: Offset) -> Result<LineInfoWatch> {
+        LineInfoWatch::new(self, offset)
+    }
+
     /// Wait for line status events on any of the watched lines on the chip.
     pub fn wait_info_event(&self, timeout: Option<Duration>) -> Result<bool> {
         let timeout = match timeout {
@@ -155,6 +180,18 @@ impl Chip {
         }
     }
 
+    /// Check whether a line status change event is immediately available on
+    /// any of the watched lines, without blocking.
+    ///
+    /// This is the non-blocking counterpart to
+    /// [`wait_info_event`](Chip::wait_info_event) - a thin wrapper around
+    /// `wait_info_event(Some(Duration::ZERO))` - meant for supervisors that
+    /// poll several chips/lines in one loop instead of parking a thread in a
+    /// blocking read.
+    pub fn poll_info_event(&self) -> Result<bool> {
+        self.wait_info_event(Some(Duration::ZERO))
+    }
+
     /// Read a single line status change event from the chip. If no events are
     /// pending, this function will block.
     pub fn read_info_event(&self) -> Result<info::Event> {
@@ -168,7 +205,36 @@ impl Chip {
             ));
         }
 
-        Ok(info::Event::new(event))
+        // SAFETY: `event` is non-null and was just obtained above; it is not
+        // used again after this call.
+        unsafe { info::Event::from_raw(event) }
+    }
+
+    /// Drain up to `max` pending line status-change events without blocking.
+    ///
+    /// libgpiod has no batched read ioctl for info events the way it does for
+    /// edge events - each one is still a separate
+    /// [`read_info_event`](Chip::read_info_event) syscall - but collecting a
+    /// whole burst here saves a caller (e.g. `gpionotify` during a
+    /// reconfiguration storm that touches many lines at once) from re-issuing
+    /// a blocking read for every single transition. Stops early, before
+    /// `max`, once [`wait_info_event`](Chip::wait_info_event) reports nothing
+    /// left to read.
+    pub fn read_info_events(
+        &self,
+        max: usize,
+    ) -> Result<impl Iterator<Item = Result<info::Event>>> {
+        let mut events = Vec::new();
+
+        while events.len() < max {
+            if !self.wait_info_event(Some(Duration::ZERO))? {
+                break;
+            }
+
+            events.push(self.read_info_event());
+        }
+
+        Ok(events.into_iter())
     }
 
     /// Map a GPIO line's name to its offset within the chip.
@@ -231,6 +297,44 @@ impl AsRawFd for Chip {
     }
 }
 
+/// RAII guard for a single watched line, returned by
+/// [`Chip::watch_line_info_guarded`].
+///
+/// Derefs to the [`line::Info`] snapshot taken when the watch started, and
+/// calls [`Chip::unwatch`] when dropped, so the watch can never outlive the
+/// scope that requested it.
+///
+/// Guards are not reference counted: each one calls [`Chip::unwatch`] for its
+/// offset when it drops, so if two guards watch the same offset, the
+/// kernel-side watch ends as soon as the first of them drops.
+pub struct LineInfoWatch<'a> {
+    chip: &'a Chip,
+    offset: Offset,
+    info: line::Info,
+}
+
+impl<'a> LineInfoWatch<'a> {
+    fn new(chip: &'a Chip, offset: Offset) -> Result<Self> {
+        let info = chip.watch_line_info(offset)?;
+
+        Ok(Self { chip, offset, info })
+    }
+}
+
+impl Deref for LineInfoWatch<'_> {
+    type Target = line::Info;
+
+    fn deref(&self) -> &line::Info {
+        &self.info
+    }
+}
+
+impl Drop for LineInfoWatch<'_> {
+    fn drop(&mut self) {
+        self.chip.unwatch(self.offset);
+    }
+}
+
 /// GPIO chip Information
 #[derive(Debug, Eq)]
 pub struct Info {
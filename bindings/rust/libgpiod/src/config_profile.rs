@@ -0,0 +1,154 @@
+// SPDX-License-Identifier: Apache-2.0 OR BSD-3-Clause
+// SPDX-FileCopyrightText: 2022 Linaro Ltd.
+// SPDX-FileCopyrightText: 2022 Viresh Kumar <viresh.kumar@linaro.org>
+
+//! Serializable line-configuration profiles, available behind the `serde`
+//! cargo feature.
+//!
+//! [`super::line::Config::to_writer`]/[`super::line::Config::from_reader`]
+//! round-trip a whole config through JSON, so a tool can describe a request
+//! (offsets, per-line settings, output values) in a file instead of
+//! hard-coding it in Rust source. The consumer name is not part of the
+//! profile; it is not saved or restored.
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    line::{Bias, Direction, Drive, Edge, EventClock, Offset, SettingVal, Settings, Value},
+    Error, Result,
+};
+
+/// A single line's settings, in a form that can be serialized to and
+/// deserialized from disk.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LineProfile {
+    pub direction: Direction,
+    pub bias: Option<Bias>,
+    pub drive: Drive,
+    pub edge_detection: Option<Edge>,
+    pub active_low: bool,
+    pub debounce_period: Duration,
+    pub event_clock: EventClock,
+    /// The output value to drive the line with. Only meaningful (and only
+    /// ever set) when `direction` is [`Direction::Output`].
+    pub output_value: Option<Value>,
+}
+
+impl LineProfile {
+    pub(crate) fn from_settings(settings: &Settings) -> Result<Self> {
+        let direction = settings.direction()?;
+
+        Ok(Self {
+            direction,
+            bias: settings.bias()?,
+            drive: settings.drive()?,
+            edge_detection: settings.edge_detection()?,
+            active_low: settings.active_low(),
+            debounce_period: settings.debounce_period()?,
+            event_clock: settings.event_clock()?,
+            output_value: if direction == Direction::Output {
+                Some(settings.output_value()?)
+            } else {
+                None
+            },
+        })
+    }
+
+    fn to_settings(&self) -> Result<Settings> {
+        let mut settings = Settings::new()?;
+
+        settings.set_prop(&[
+            SettingVal::Direction(self.direction),
+            SettingVal::Bias(self.bias),
+            SettingVal::Drive(self.drive),
+            SettingVal::EdgeDetection(self.edge_detection),
+            SettingVal::ActiveLow(self.active_low),
+            SettingVal::DebouncePeriod(self.debounce_period),
+            SettingVal::EventClock(self.event_clock),
+        ])?;
+
+        if let Some(value) = self.output_value {
+            settings.set_output_value(value)?;
+        }
+
+        Ok(settings)
+    }
+}
+
+impl TryFrom<&LineProfile> for Settings {
+    type Error = super::Error;
+
+    /// Build a [`Settings`] object from a deserialized [`LineProfile`].
+    fn try_from(profile: &LineProfile) -> Result<Self> {
+        profile.to_settings()
+    }
+}
+
+/// A declarative snapshot of a [`super::line::Config`], suitable for saving
+/// to and loading from a config file.
+///
+/// Built on top of [`super::line::Config::line_settings`], so it mirrors the
+/// offset-to-settings mapping of the requested lines, not the requested line
+/// order.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ConfigProfile {
+    pub lines: BTreeMap<Offset, LineProfile>,
+}
+
+impl super::line::Config {
+    /// Dump this config's offset-to-settings mapping into a [`ConfigProfile`].
+    pub fn to_profile(&self) -> Result<ConfigProfile> {
+        let mut lines = BTreeMap::new();
+
+        for (offset, settings) in self.line_settings()? {
+            lines.insert(offset, LineProfile::from_settings(&settings)?);
+        }
+
+        Ok(ConfigProfile { lines })
+    }
+
+    /// Load a line configuration previously saved with
+    /// [`to_writer`](Self::to_writer).
+    ///
+    /// Round-trips through [`ConfigProfile`] as JSON; see
+    /// [`Self::from_profile`] for how output values are mapped back onto
+    /// offsets.
+    pub fn from_reader<R: std::io::Read>(reader: R) -> Result<Self> {
+        let profile: ConfigProfile =
+            serde_json::from_reader(reader).map_err(|err| Error::SerdeError(err.to_string()))?;
+        Self::from_profile(&profile)
+    }
+
+    /// Save this config's offset-to-settings mapping as JSON, so it can be
+    /// loaded back later with [`from_reader`](Self::from_reader).
+    ///
+    /// See [`Self::to_profile`].
+    pub fn to_writer<W: std::io::Write>(&self, writer: W) -> Result<()> {
+        serde_json::to_writer_pretty(writer, &self.to_profile()?)
+            .map_err(|err| Error::SerdeError(err.to_string()))
+    }
+
+    /// Build a config by replaying a [`ConfigProfile`]'s offsets and settings.
+    ///
+    /// Output values are applied in one pass after all offsets have been
+    /// added, matching how [`super::line::Config::set_output_values`] maps
+    /// values onto the offsets in the order they were configured.
+    pub fn from_profile(profile: &ConfigProfile) -> Result<Self> {
+        let mut config = Self::new()?;
+        let mut output_values = Vec::with_capacity(profile.lines.len());
+
+        for (offset, line) in &profile.lines {
+            config.add_line_settings(&[*offset], line.to_settings()?)?;
+            output_values.push(line.output_value.unwrap_or(Value::InActive));
+        }
+
+        if !output_values.is_empty() {
+            config.set_output_values(&output_values)?;
+        }
+
+        Ok(config)
+    }
+}
@@ -2,14 +2,24 @@
 // SPDX-FileCopyrightText: 2022 Linaro Ltd.
 // SPDX-FileCopyrightText: 2022 Viresh Kumar <viresh.kumar@linaro.org>
 
-use std::time::Duration;
+use std::time::{Duration, SystemTime};
 
 use super::{
     gpiod,
-    line::{EdgeKind, Offset},
+    line::{EdgeKind, EventClock, Offset},
     Error, OperationType, Result,
 };
 
+/// Convert a `timestamp` captured under `clock` into a wall-clock time.
+///
+/// Returns `None` unless `clock` is [`EventClock::Realtime`]: that is the
+/// only clock whose epoch lines up with [`SystemTime::UNIX_EPOCH`] -
+/// monotonic and hardware-latched (HTE) timestamps have no defined
+/// relationship to wall-clock time.
+fn timestamp_systemtime(timestamp: Duration, clock: EventClock) -> Option<SystemTime> {
+    (clock == EventClock::Realtime).then(|| SystemTime::UNIX_EPOCH + timestamp)
+}
+
 /// Line edge events handling
 ///
 /// An edge event object contains information about a single line edge event.
@@ -24,6 +34,41 @@ use super::{
 #[derive(Debug, Eq, PartialEq)]
 pub struct Event(*mut gpiod::gpiod_edge_event);
 
+/// A cheap, owned snapshot of an [`Event`]'s fields.
+///
+/// Unlike [`Event`], this holds no pointer into C-allocated memory: it is a
+/// plain value type, so producing one with [`Event::snapshot`] needs no
+/// `gpiod_edge_event_copy` allocation and dropping it needs no matching free.
+/// This makes it cheap to drain a whole buffer of events into a
+/// `Vec<EdgeEventData>` for storage or cross-thread use. With the `serde`
+/// cargo feature enabled it also implements `Serialize`/`Deserialize`, so
+/// recorded events can be persisted (e.g. as JSON or MessagePack) and read
+/// back without a live chip.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EdgeEventData {
+    /// The event type.
+    pub event_type: EdgeKind,
+    /// The timestamp of the event.
+    pub timestamp: Duration,
+    /// The offset of the line on which the event was triggered.
+    pub line_offset: Offset,
+    /// The global sequence number of the event.
+    pub global_seqno: usize,
+    /// The event sequence number specific to the concerned line.
+    pub line_seqno: usize,
+}
+
+impl EdgeEventData {
+    /// Convert `self.timestamp` into a wall-clock [`SystemTime`].
+    ///
+    /// See [`Event::timestamp_systemtime`] for the clock-threading caveat
+    /// this shares.
+    pub fn timestamp_systemtime(&self, clock: EventClock) -> Option<SystemTime> {
+        timestamp_systemtime(self.timestamp, clock)
+    }
+}
+
 impl Event {
     pub fn event_clone(event: &Event) -> Result<Event> {
         // SAFETY: `gpiod_edge_event` is guaranteed to be valid here.
@@ -45,11 +90,29 @@ impl Event {
     }
 
     /// Get the timestamp of the event.
+    ///
+    /// The C event object does not carry which clock produced the timestamp -
+    /// that is a property of the [`EventClock`](crate::line::EventClock) the
+    /// line was requested with. Callers that need to reason about jitter
+    /// should fetch the clock used for the request (e.g. via
+    /// `Settings::event_clock()`) and check
+    /// [`EventClock::is_hardware()`](crate::line::EventClock::is_hardware) to
+    /// tell hardware-latched timestamps from software-sampled ones.
     pub fn timestamp(&self) -> Duration {
         // SAFETY: `gpiod_edge_event` is guaranteed to be valid here.
         Duration::from_nanos(unsafe { gpiod::gpiod_edge_event_get_timestamp_ns(self.0) })
     }
 
+    /// Convert this event's timestamp into a wall-clock [`SystemTime`].
+    ///
+    /// `clock` must be the [`EventClock`] the line was requested with - the
+    /// event object itself does not carry it, so the caller must thread it
+    /// through from the `Settings`/`Config` used to make the request. Returns
+    /// `None` unless `clock` is [`EventClock::Realtime`].
+    pub fn timestamp_systemtime(&self, clock: EventClock) -> Option<SystemTime> {
+        timestamp_systemtime(self.timestamp(), clock)
+    }
+
     /// Get the offset of the line on which the event was triggered.
     pub fn line_offset(&self) -> Offset {
         // SAFETY: `gpiod_edge_event` is guaranteed to be valid here.
@@ -81,6 +144,20 @@ impl Event {
                 .unwrap()
         }
     }
+
+    /// Take a cheap, owned snapshot of this event's fields.
+    ///
+    /// Unlike [`Event::event_clone`], this performs no C allocation - it just
+    /// copies the already-cached scalar fields into an [`EdgeEventData`].
+    pub fn snapshot(&self) -> Result<EdgeEventData> {
+        Ok(EdgeEventData {
+            event_type: self.event_type()?,
+            timestamp: self.timestamp(),
+            line_offset: self.line_offset(),
+            global_seqno: self.global_seqno(),
+            line_seqno: self.line_seqno(),
+        })
+    }
 }
 
 impl Drop for Event {
@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: Apache-2.0 OR BSD-3-Clause
+// SPDX-FileCopyrightText: 2022 Linaro Ltd.
+// SPDX-FileCopyrightText: 2022 Viresh Kumar <viresh.kumar@linaro.org>
+
+use std::collections::VecDeque;
+
+use super::{
+    request::{Buffer, Event, Request},
+    Result,
+};
+
+/// A self-contained, blocking iterator over a line request's edge events.
+///
+/// Owns its [`Buffer`] and refills it with `wait_edge_events(None)` +
+/// `read_edge_events` whenever it runs dry, so callers no longer need to
+/// thread a buffer through their own event loop. Each refill clones every
+/// event it read out of the buffer into an internal queue, so each one
+/// returned by `next()`/`nth()` remains valid independently of later refills.
+pub struct EdgeEventIter<'a> {
+    request: &'a Request,
+    buffer: Buffer,
+    pending: VecDeque<Event>,
+}
+
+impl<'a> EdgeEventIter<'a> {
+    pub(crate) fn new(request: &'a Request, capacity: usize) -> Result<Self> {
+        Ok(Self {
+            request,
+            buffer: Buffer::new(capacity)?,
+            pending: VecDeque::new(),
+        })
+    }
+
+    /// Block until new events are queued and decode them into `pending`.
+    fn refill(&mut self) -> Result<()> {
+        self.request.wait_edge_events(None)?;
+
+        for event in self.buffer.read_edge_events(self.request)? {
+            self.pending.push_back(Event::event_clone(event?)?);
+        }
+
+        Ok(())
+    }
+}
+
+impl Iterator for EdgeEventIter<'_> {
+    type Item = Result<Event>;
+
+    fn nth(&mut self, mut n: usize) -> Option<Self::Item> {
+        loop {
+            if n < self.pending.len() {
+                self.pending.drain(0..n);
+                return self.pending.pop_front().map(Ok);
+            }
+
+            n -= self.pending.len();
+            self.pending.clear();
+
+            if let Err(err) = self.refill() {
+                return Some(Err(err));
+            }
+        }
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // clippy false-positive, fixed in next clippy release:
+        // https://github.com/rust-lang/rust-clippy/issues/9820
+        #[allow(clippy::iter_nth_zero)]
+        self.nth(0)
+    }
+}
@@ -0,0 +1,149 @@
+// SPDX-License-Identifier: Apache-2.0 OR BSD-3-Clause
+// SPDX-FileCopyrightText: 2022 Linaro Ltd.
+// SPDX-FileCopyrightText: 2022 Viresh Kumar <viresh.kumar@linaro.org>
+
+//! Async edge-event stream, available behind the `tokio` cargo feature.
+
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures_core::Stream;
+use tokio::io::unix::AsyncFd;
+
+use super::{
+    edge_event::EdgeEventData,
+    fd::NonBlockingGuard,
+    request::{Buffer, Request},
+    Error, Result,
+};
+
+/// An async adapter that turns a line request's edge events into a
+/// [`Stream`].
+///
+/// The stream owns its [`Buffer`] (sized with the same semantics as
+/// [`Buffer::new`]) and, whenever the request's fd becomes readable, keeps
+/// reading it into an internal queue until the kernel has no more events
+/// queued, and only then re-arms readiness. This makes sure a single
+/// readable notification that
+/// delivered several events (or a buffer filled beyond its capacity) does not
+/// drop any of them, and that the stream parks instead of busy-looping when
+/// no events are pending.
+///
+/// Yielded items are owned [`EdgeEventData`] snapshots rather than
+/// [`Event`](super::edge_event::Event), so draining the buffer needs no
+/// `gpiod_edge_event_copy` allocation per event.
+pub struct EdgeEventStream<'a> {
+    request: &'a Request,
+    async_fd: AsyncFd<i32>,
+    buffer: Buffer,
+    pending: VecDeque<EdgeEventData>,
+    _nonblocking: NonBlockingGuard,
+}
+
+impl<'a> EdgeEventStream<'a> {
+    /// Create a new stream over `request`'s edge events.
+    ///
+    /// `capacity` is forwarded to [`Buffer::new`].
+    pub fn new(request: &'a Request, capacity: usize) -> Result<Self> {
+        use std::os::unix::io::AsRawFd;
+
+        let nonblocking = NonBlockingGuard::new(request.as_raw_fd())?;
+
+        Ok(Self {
+            request,
+            async_fd: AsyncFd::new(request.as_raw_fd()).map_err(Error::IoError)?,
+            buffer: Buffer::new(capacity)?,
+            pending: VecDeque::new(),
+            _nonblocking: nonblocking,
+        })
+    }
+}
+
+/// Await until at least one edge event is queued for `request`, then drain
+/// it into `buffer`.
+///
+/// This is the one-shot counterpart to [`EdgeEventStream`], for callers that
+/// just want `wait_edge_events(None)` + `read_edge_events` without paying for
+/// a long-lived stream.
+pub async fn read_edge_events_async<'a>(
+    request: &Request,
+    buffer: &'a mut Buffer,
+) -> Result<super::request::Events<'a>> {
+    use std::os::unix::io::AsRawFd;
+
+    let _nonblocking = NonBlockingGuard::new(request.as_raw_fd())?;
+    let async_fd = AsyncFd::new(request.as_raw_fd()).map_err(Error::IoError)?;
+
+    loop {
+        let mut guard = async_fd.readable().await.map_err(Error::IoError)?;
+
+        if request.wait_edge_events(Some(Duration::ZERO))? {
+            break;
+        }
+
+        // Spurious wakeup - nothing queued yet.
+        guard.clear_ready();
+    }
+
+    buffer.read_edge_events(request)
+}
+
+impl Stream for EdgeEventStream<'_> {
+    type Item = Result<EdgeEventData>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        if let Some(event) = this.pending.pop_front() {
+            return Poll::Ready(Some(Ok(event)));
+        }
+
+        loop {
+            let mut guard = match this.async_fd.poll_read_ready(cx) {
+                Poll::Ready(Ok(guard)) => guard,
+                Poll::Ready(Err(err)) => return Poll::Ready(Some(Err(Error::IoError(err)))),
+                Poll::Pending => return Poll::Pending,
+            };
+
+            match this.request.wait_edge_events(Some(Duration::ZERO)) {
+                Ok(true) => {}
+                Ok(false) => {
+                    // Spurious wakeup: nothing is actually queued yet, so
+                    // re-arm readiness and keep waiting instead of yielding.
+                    guard.clear_ready();
+                    continue;
+                }
+                Err(err) => return Poll::Ready(Some(Err(err))),
+            }
+
+            let events = match this.buffer.read_edge_events(this.request) {
+                Ok(events) => events,
+                Err(err) => return Poll::Ready(Some(Err(err))),
+            };
+
+            for event in events {
+                match event.and_then(|event| event.snapshot()) {
+                    Ok(data) => this.pending.push_back(data),
+                    Err(err) => return Poll::Ready(Some(Err(err))),
+                }
+            }
+
+            // Only clear readiness once the kernel buffer has been fully
+            // drained - a single notification can carry more events than fit
+            // in one `Buffer`, and we must not stop polling until it is dry.
+            match this.request.wait_edge_events(Some(Duration::ZERO)) {
+                Ok(true) => continue,
+                Ok(false) => {}
+                Err(err) => return Poll::Ready(Some(Err(err))),
+            }
+
+            guard.clear_ready();
+
+            if let Some(event) = this.pending.pop_front() {
+                return Poll::Ready(Some(Ok(event)));
+            }
+        }
+    }
+}
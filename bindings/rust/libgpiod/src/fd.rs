@@ -0,0 +1,63 @@
+// SPDX-License-Identifier: Apache-2.0 OR BSD-3-Clause
+// SPDX-FileCopyrightText: 2022 Linaro Ltd.
+// SPDX-FileCopyrightText: 2022 Viresh Kumar <viresh.kumar@linaro.org>
+
+use super::Result;
+
+/// Puts `fd` into non-blocking mode for as long as the guard lives,
+/// restoring the fd's original flags on drop.
+///
+/// Async readiness notifications - whether from `tokio::io::unix::AsyncFd`
+/// or this crate's own `epoll`-based reactor (behind the `reactor` cargo
+/// feature) - only mean "the kernel buffer had at least one event at some
+/// point in the recent past", not "still does". Without `O_NONBLOCK`, racing
+/// another waiter (or a spurious wakeup) could make the underlying blocking
+/// read stall the whole reactor instead of returning `EWOULDBLOCK`.
+///
+/// Every async adapter in this crate wraps a fd owned by a
+/// [`Request`](super::request::Request)/[`Chip`](super::chip::Chip) that
+/// outlives the adapter, and every sync API on that same object
+/// (`Buffer::read_edge_events`, `Chip::read_info_event`,
+/// `wait_edge_events(None)`) documents blocking semantics on that fd. Tying
+/// the non-blocking switch to this guard's lifetime - instead of flipping
+/// the flag once and leaving it - means a caller that uses the async and
+/// sync APIs on the same fd at different times (never concurrently) gets
+/// its fd back in blocking mode once the adapter holding the guard is
+/// dropped, rather than being silently and permanently switched over.
+pub(crate) struct NonBlockingGuard {
+    fd: i32,
+    original_flags: i32,
+}
+
+impl NonBlockingGuard {
+    pub(crate) fn new(fd: i32) -> Result<Self> {
+        // SAFETY: `fd` is a valid, open file descriptor for the lifetime of
+        // this call - it is borrowed from a live `Request`/`Chip`.
+        let original_flags = unsafe { libc::fcntl(fd, libc::F_GETFL) };
+        if original_flags == -1 {
+            return Err(std::io::Error::last_os_error().into());
+        }
+
+        if original_flags & libc::O_NONBLOCK == 0 {
+            // SAFETY: see above.
+            let ret =
+                unsafe { libc::fcntl(fd, libc::F_SETFL, original_flags | libc::O_NONBLOCK) };
+            if ret == -1 {
+                return Err(std::io::Error::last_os_error().into());
+            }
+        }
+
+        Ok(Self { fd, original_flags })
+    }
+}
+
+impl Drop for NonBlockingGuard {
+    fn drop(&mut self) {
+        if self.original_flags & libc::O_NONBLOCK == 0 {
+            // SAFETY: see `NonBlockingGuard::new`. A failure here just
+            // leaves the fd in non-blocking mode - there is nothing more to
+            // do with it in a `Drop` impl.
+            unsafe { libc::fcntl(self.fd, libc::F_SETFL, self.original_flags) };
+        }
+    }
+}
@@ -18,16 +18,23 @@ use super::{
 /// exposed by GPIO chips. Each info event contains information about the event
 /// itself (timestamp, type) as well as a snapshot of line's state in the form
 /// of a line-info object.
-
-#[derive(Debug, Eq, PartialEq)]
+///
+/// Unlike [`edge_event::Event`](super::edge_event::Event), this holds no
+/// pointer into the source `gpiod_info_event` once constructed: the event
+/// type and timestamp are plain scalars, and the line-info snapshot is an
+/// owned [`line::Info`] (its own copy of the `gpiod_line_info`) rather than a
+/// borrow into the event.
+/// This decouples a retained `Event` from the read that produced it, so
+/// draining a chip with [`Chip::read_info_event`](super::chip::Chip::read_info_event)
+/// in a loop and stashing the results (e.g. in a `Vec` or across a channel)
+/// needs no care about a later read invalidating an earlier one.
+#[derive(Debug)]
 pub struct Event {
-    pub(crate) event: *mut gpiod::gpiod_info_event,
+    event_type: InfoChangeKind,
+    timestamp: Duration,
+    line_info: line::Info,
 }
 
-// SAFETY: Event models a wrapper around an owned gpiod_info_event and may be
-// safely sent to other threads.
-unsafe impl Send for Event {}
-
 impl Event {
     /// Get a single chip's line's status change event.
     ///
@@ -35,46 +42,74 @@ impl Event {
     /// constructing an [Event] the pointer MUST NOT be used for any other
     /// purpose anymore. All interactions with the libgpiod API have to happen
     /// through this object.
-    pub(crate) unsafe fn from_raw(event: *mut gpiod::gpiod_info_event) -> Self {
-        Self { event }
+    pub(crate) unsafe fn from_raw(event: *mut gpiod::gpiod_info_event) -> Result<Self> {
+        // SAFETY: `gpiod_info_event` is guaranteed to be valid here.
+        let event_type =
+            InfoChangeKind::new(unsafe { gpiod::gpiod_info_event_get_event_type(event) })?;
+        // SAFETY: `gpiod_info_event` is guaranteed to be valid here.
+        let timestamp =
+            Duration::from_nanos(unsafe { gpiod::gpiod_info_event_get_timestamp_ns(event) });
+
+        // SAFETY: `gpiod_info_event` is guaranteed to be valid here.
+        let info = unsafe { gpiod::gpiod_info_event_get_line_info(event) };
+        if info.is_null() {
+            // SAFETY: `gpiod_info_event` is guaranteed to be valid here.
+            unsafe { gpiod::gpiod_info_event_free(event) };
+            return Err(Error::OperationFailed(
+                OperationType::InfoEventGetLineInfo,
+                errno::errno(),
+            ));
+        }
+
+        // SAFETY: The pointer is valid and owned by `event` for as long as
+        // `event` is not freed.
+        let line_info = unsafe { line::InfoRef::from_raw(info) }.try_clone();
+
+        // SAFETY: `gpiod_info_event` is guaranteed to be valid here. Having
+        // already cloned the embedded line-info above, freeing the event
+        // here does not affect `line_info`'s independent allocation.
+        unsafe { gpiod::gpiod_info_event_free(event) };
+
+        Ok(Self {
+            event_type,
+            timestamp,
+            line_info: line_info?,
+        })
     }
 
     /// Get the event type of the status change event.
     pub fn event_type(&self) -> Result<InfoChangeKind> {
-        // SAFETY: `gpiod_info_event` is guaranteed to be valid here.
-        InfoChangeKind::new(unsafe { gpiod::gpiod_info_event_get_event_type(self.event) })
+        Ok(self.event_type)
     }
 
     /// Get the timestamp of the event, read from the monotonic clock.
     pub fn timestamp(&self) -> Duration {
-        // SAFETY: `gpiod_info_event` is guaranteed to be valid here.
-        Duration::from_nanos(unsafe { gpiod::gpiod_info_event_get_timestamp_ns(self.event) })
+        self.timestamp
     }
 
     /// Get the line-info object associated with the event.
     pub fn line_info(&self) -> Result<&line::InfoRef> {
-        // SAFETY: `gpiod_line_info` is guaranteed to be valid here.
-        let info = unsafe { gpiod::gpiod_info_event_get_line_info(self.event) };
-
-        if info.is_null() {
-            return Err(Error::OperationFailed(
-                OperationType::InfoEventGetLineInfo,
-                errno::errno(),
-            ));
-        }
-
-        // SAFETY: The pointer is valid. The returned reference receives the
-        // lifetime '0 - the same as &self. &self also controls lifetime and
-        // ownership of the owning object. Therefore, the borrow prevents moving
-        // of the owning object to another thread.
-        Ok(unsafe { line::InfoRef::from_raw(info) })
+        Ok(&self.line_info)
     }
 }
 
-impl Drop for Event {
-    /// Free the info event object and release all associated resources.
-    fn drop(&mut self) {
-        // SAFETY: `gpiod_info_event` is guaranteed to be valid here.
-        unsafe { gpiod::gpiod_info_event_free(self.event) }
+impl Clone for Event {
+    /// Clone this event, deep-copying its embedded line-info snapshot.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the underlying `gpiod_line_info_copy` allocation fails.
+    /// Callers that need to handle that failure instead of aborting can call
+    /// `event.line_info()?.try_clone()` directly and rebuild the pieces they
+    /// need from its `Result`.
+    fn clone(&self) -> Self {
+        Self {
+            event_type: self.event_type,
+            timestamp: self.timestamp,
+            line_info: self
+                .line_info
+                .try_clone()
+                .expect("failed to clone embedded line info"),
+        }
     }
 }
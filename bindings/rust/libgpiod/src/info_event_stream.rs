@@ -0,0 +1,104 @@
+// SPDX-License-Identifier: Apache-2.0 OR BSD-3-Clause
+// SPDX-FileCopyrightText: 2022 Linaro Ltd.
+// SPDX-FileCopyrightText: 2022 Viresh Kumar <viresh.kumar@linaro.org>
+
+//! Async chip info-event stream, available behind the `tokio` cargo feature.
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures_core::Stream;
+use tokio::io::unix::AsyncFd;
+
+use super::{
+    chip::{info::Event, Chip},
+    fd::NonBlockingGuard,
+    Error, Result,
+};
+
+/// An async adapter that turns a chip's line status-change events into a
+/// [`Stream`].
+///
+/// Mirrors [`EdgeEventStream`](super::request::EdgeEventStream): the chip fd
+/// is readable whenever at least one info event is queued, so `poll_next`
+/// waits for fd readiness, checks with a zero-timeout
+/// [`Chip::wait_info_event`] that an event is really queued, and only then
+/// calls [`Chip::read_info_event`]; on a spurious wakeup it clears readiness
+/// and waits again. Dropping the stream does not close the fd - it
+/// is borrowed from, and owned by, the originating [`Chip`].
+pub struct InfoEventStream<'a> {
+    chip: &'a Chip,
+    async_fd: AsyncFd<i32>,
+    _nonblocking: NonBlockingGuard,
+}
+
+impl<'a> InfoEventStream<'a> {
+    /// Create a new stream over `chip`'s line status-change events.
+    ///
+    /// `chip` must already be watching at least one line (see
+    /// [`Chip::watch_line_info`]) for this to ever yield anything.
+    pub fn new(chip: &'a Chip) -> Result<Self> {
+        use std::os::unix::io::AsRawFd;
+
+        let nonblocking = NonBlockingGuard::new(chip.as_raw_fd())?;
+
+        Ok(Self {
+            chip,
+            async_fd: AsyncFd::new(chip.as_raw_fd()).map_err(Error::IoError)?,
+            _nonblocking: nonblocking,
+        })
+    }
+}
+
+/// Await until an info event is queued for `chip`, then read it.
+///
+/// This is the one-shot counterpart to [`InfoEventStream`], for callers that
+/// just want a single `async fn read_info_event()` without paying for a
+/// long-lived stream.
+pub async fn read_info_event_async(chip: &Chip) -> Result<Event> {
+    use std::os::unix::io::AsRawFd;
+
+    let _nonblocking = NonBlockingGuard::new(chip.as_raw_fd())?;
+    let async_fd = AsyncFd::new(chip.as_raw_fd()).map_err(Error::IoError)?;
+
+    loop {
+        let mut guard = async_fd.readable().await.map_err(Error::IoError)?;
+
+        if chip.wait_info_event(Some(Duration::ZERO))? {
+            return chip.read_info_event();
+        }
+
+        // Spurious wakeup - nothing queued yet.
+        guard.clear_ready();
+    }
+}
+
+impl Stream for InfoEventStream<'_> {
+    type Item = Result<Event>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            let mut guard = match this.async_fd.poll_read_ready(cx) {
+                Poll::Ready(Ok(guard)) => guard,
+                Poll::Ready(Err(err)) => return Poll::Ready(Some(Err(Error::IoError(err)))),
+                Poll::Pending => return Poll::Pending,
+            };
+
+            match this.chip.wait_info_event(Some(Duration::ZERO)) {
+                Ok(true) => {}
+                Ok(false) => {
+                    // Spurious wakeup: nothing is actually queued yet, so
+                    // re-arm readiness and keep waiting instead of yielding.
+                    guard.clear_ready();
+                    continue;
+                }
+                Err(err) => return Poll::Ready(Some(Err(err))),
+            }
+
+            return Poll::Ready(Some(this.chip.read_info_event()));
+        }
+    }
+}
@@ -95,6 +95,8 @@ pub enum OperationType {
     LineSettingsSetOutputValue,
     RequestConfigNew,
     RequestConfigGetConsumer,
+    ReactorEpollCtl,
+    SelectorPoll,
     SimBankGetVal,
     SimBankNew,
     SimBankSetLabel,
@@ -118,50 +120,125 @@ impl fmt::Display for OperationType {
 pub type Result<T> = std::result::Result<T, Error>;
 
 /// Error codes for libgpiod operations.
-#[derive(Copy, Clone, Debug, Eq, PartialEq, ThisError)]
+#[derive(Debug, ThisError)]
 pub enum Error {
     #[error("Failed to get {0}")]
     NullString(&'static str),
     #[error("String not utf8: {0:?}")]
-    StringNotUtf8(str::Utf8Error),
+    StringNotUtf8(#[source] str::Utf8Error),
     #[error("Invalid String")]
     InvalidString,
     #[error("Invalid enum {0} value: {1}")]
     InvalidEnumValue(&'static str, i32),
     #[error("Operation {0} Failed: {1}")]
-    OperationFailed(OperationType, errno::Errno),
+    OperationFailed(OperationType, #[source] errno::Errno),
     #[error("Invalid Arguments")]
     InvalidArguments,
+    #[error("Invalid line settings: {0}")]
+    InvalidSettings(&'static str),
+    #[error("Line not found: {0}")]
+    LineNotFound(String),
+    #[cfg(feature = "serde")]
+    #[error("(De)serialization failed: {0}")]
+    SerdeError(String),
     #[error("Event count more than buffer capacity: {0} > {1}")]
     TooManyEvents(usize, usize),
-    #[error("Std Io Error")]
-    IoError,
+    #[error("I/O error: {0}")]
+    IoError(#[source] std::io::Error),
+    #[cfg(feature = "reactor")]
+    #[error("Reactor fd token pool exhausted (max {0} concurrent registrations)")]
+    ReactorTokensExhausted(usize),
 }
 
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Error::IoError(err)
+    }
+}
+
+// `std::io::Error` carries no `PartialEq`/`Eq`/`Clone`, so those can no
+// longer be derived wholesale now that `IoError` holds a real one. Compare
+// it by `ErrorKind` instead of message, since that is the part applications
+// actually branch on (e.g. distinguishing `PermissionDenied` from
+// `NotFound`) - every other variant compares exactly as `derive(PartialEq)`
+// would have.
+impl PartialEq for Error {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::NullString(a), Self::NullString(b)) => a == b,
+            (Self::StringNotUtf8(a), Self::StringNotUtf8(b)) => a == b,
+            (Self::InvalidString, Self::InvalidString) => true,
+            (Self::InvalidEnumValue(a0, a1), Self::InvalidEnumValue(b0, b1)) => {
+                a0 == b0 && a1 == b1
+            }
+            (Self::OperationFailed(a0, a1), Self::OperationFailed(b0, b1)) => {
+                a0 == b0 && a1 == b1
+            }
+            (Self::InvalidArguments, Self::InvalidArguments) => true,
+            (Self::InvalidSettings(a), Self::InvalidSettings(b)) => a == b,
+            (Self::LineNotFound(a), Self::LineNotFound(b)) => a == b,
+            #[cfg(feature = "serde")]
+            (Self::SerdeError(a), Self::SerdeError(b)) => a == b,
+            (Self::TooManyEvents(a0, a1), Self::TooManyEvents(b0, b1)) => a0 == b0 && a1 == b1,
+            (Self::IoError(a), Self::IoError(b)) => a.kind() == b.kind(),
+            #[cfg(feature = "reactor")]
+            (Self::ReactorTokensExhausted(a), Self::ReactorTokensExhausted(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Error {}
+
 mod info_event;
 
 /// GPIO chip related definitions.
 pub mod chip;
 
+/// Multi-line edge monitoring subsystem built on [`chip`] and [`request`].
+pub mod monitor;
+
 mod edge_event;
+mod edge_event_iter;
+#[cfg(feature = "tokio")]
+mod edge_event_stream;
 mod event_buffer;
+#[cfg(any(feature = "tokio", feature = "reactor"))]
+mod fd;
+#[cfg(feature = "tokio")]
+mod info_event_stream;
 mod line_request;
+#[cfg(feature = "reactor")]
+mod reactor;
 mod request_config;
+mod selector;
+mod shared_request;
 
 /// GPIO chip request related definitions.
 pub mod request {
     pub use crate::edge_event::*;
+    pub use crate::edge_event_iter::*;
+    #[cfg(feature = "tokio")]
+    pub use crate::edge_event_stream::*;
     pub use crate::event_buffer::*;
     pub use crate::line_request::*;
+    #[cfg(feature = "reactor")]
+    pub use crate::reactor::*;
     pub use crate::request_config::*;
+    pub use crate::selector::*;
+    pub use crate::shared_request::*;
 }
 
+#[cfg(feature = "serde")]
+mod config_profile;
 mod line_config;
 mod line_info;
 mod line_settings;
 
 /// GPIO chip line related definitions.
 pub mod line {
+    #[cfg(feature = "serde")]
+    pub use crate::config_profile::*;
     pub use crate::line_config::*;
     pub use crate::line_info::*;
     pub use crate::line_settings::*;
@@ -169,7 +246,8 @@ pub mod line {
     use super::*;
 
     /// Value settings.
-    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub enum Value {
         /// Active
         Active,
@@ -204,13 +282,22 @@ pub mod line {
                 Value::InActive => GPIOD_LINE_VALUE_INACTIVE,
             }
         }
+
+        /// Get the inverse of this value.
+        pub fn toggled(&self) -> Self {
+            match self {
+                Value::Active => Value::InActive,
+                Value::InActive => Value::Active,
+            }
+        }
     }
 
     /// Offset type.
     pub type Offset = u32;
 
     /// Direction settings.
-    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub enum Direction {
         /// Request the line(s), but don't change direction.
         AsIs,
@@ -240,7 +327,8 @@ pub mod line {
     }
 
     /// Internal bias settings.
-    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub enum Bias {
         /// The internal bias is disabled.
         Disabled,
@@ -275,7 +363,8 @@ pub mod line {
     }
 
     /// Drive settings.
-    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub enum Drive {
         /// Drive setting is push-pull.
         PushPull,
@@ -305,7 +394,8 @@ pub mod line {
     }
 
     /// Edge detection settings.
-    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub enum Edge {
         /// Line detects rising edge events.
         Rising,
@@ -340,6 +430,7 @@ pub mod line {
 
     /// Line setting kind.
     #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub enum SettingKind {
         /// Line direction.
         Direction,
@@ -361,6 +452,7 @@ pub mod line {
 
     /// Line settings.
     #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub enum SettingVal {
         /// Line direction.
         Direction(Direction),
@@ -387,7 +479,8 @@ pub mod line {
     }
 
     /// Event clock settings.
-    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub enum EventClock {
         /// Line uses the monotonic clock for edge event timestamps.
         Monotonic,
@@ -414,6 +507,16 @@ pub mod line {
                 EventClock::HTE => GPIOD_LINE_CLOCK_HTE,
             }
         }
+
+        /// Returns true if the clock is latched by a hardware timestamping engine
+        /// rather than sampled by kernel software.
+        ///
+        /// Hardware-latched timestamps are not subject to the scheduling jitter
+        /// that software sampling incurs, so callers reasoning about event timing
+        /// precision can use this to tell the two apart.
+        pub fn is_hardware(&self) -> bool {
+            matches!(self, EventClock::HTE)
+        }
     }
 
     /// Line status change event types.
@@ -439,7 +542,8 @@ pub mod line {
     }
 
     /// Edge event types.
-    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub enum EdgeKind {
         /// Rising edge event.
         Rising,
@@ -480,7 +584,7 @@ pub fn is_gpiochip_device<P: AsRef<Path>>(path: &P) -> bool {
 pub fn gpiochip_devices<P: AsRef<Path>>(path: &P) -> Result<Vec<chip::Chip>> {
     let mut devices = Vec::new();
 
-    for entry in fs::read_dir(path).map_err(|_| Error::IoError)?.flatten() {
+    for entry in fs::read_dir(path)?.flatten() {
         let path = entry.path();
 
         if is_gpiochip_device(&path) {
@@ -497,6 +601,23 @@ pub fn gpiochip_devices<P: AsRef<Path>>(path: &P) -> Result<Vec<chip::Chip>> {
     Ok(devices.into_iter().map(|a| a.0).collect())
 }
 
+/// Scan every GPIO chip under `path` (see [`gpiochip_devices`]) for a line
+/// named `name`, and return the chip it belongs to along with its offset.
+///
+/// Line names are not guaranteed unique, even on a single chip, so this
+/// returns the first match in [`gpiochip_devices`]'s chip order. Useful for
+/// portable tools, since it lets board revisions renumber `/dev/gpiochipN`
+/// without breaking line lookups that only know the name.
+pub fn find_line<P: AsRef<Path>>(path: &P, name: &str) -> Result<(chip::Chip, line::Offset)> {
+    for chip in gpiochip_devices(path)? {
+        if let Ok(offset) = chip.line_offset_from_name(name) {
+            return Ok((chip, offset));
+        }
+    }
+
+    Err(Error::LineNotFound(name.to_owned()))
+}
+
 /// Get the API version of the libgpiod library as a human-readable string.
 pub fn libgpiod_version() -> Result<&'static str> {
     // SAFETY: The string returned by libgpiod is guaranteed to live forever.
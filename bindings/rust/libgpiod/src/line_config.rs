@@ -4,9 +4,22 @@
 
 use super::{
     Error, OperationType, Result, gpiod,
-    line::{Offset, Settings, SettingsMap, Value},
+    line::{Offset, SettingKind, Settings, SettingsMap, Value},
 };
 
+/// All the individually comparable properties of a [`Settings`] object, used
+/// by [`Config::diff`] to detect whether a line actually needs reconfiguring.
+const SETTING_KINDS: [SettingKind; 8] = [
+    SettingKind::Direction,
+    SettingKind::Bias,
+    SettingKind::Drive,
+    SettingKind::EdgeDetection,
+    SettingKind::ActiveLow,
+    SettingKind::DebouncePeriod,
+    SettingKind::EventClock,
+    SettingKind::OutputValue,
+];
+
 /// Line configuration objects.
 ///
 /// The line-config object contains the configuration for lines that can be
@@ -143,6 +156,66 @@ impl Config {
 
         Ok(map)
     }
+
+    /// Build a new config containing only the offsets whose settings in this
+    /// config differ from `current` (typically the `SettingsMap` of an
+    /// already-requested set of lines, as returned by `line_settings()`), and
+    /// return it together with a list of the offsets that `current` has
+    /// settings for but this config does not.
+    ///
+    /// Offsets configured here but absent from `current` are always
+    /// included in the returned `Config`, since they have never been
+    /// applied. Offsets whose settings compare equal to `current`
+    /// property-by-property are left out, so reconfiguring with the result
+    /// touches the minimum set of lines. An empty `Config` is returned if
+    /// nothing changed; the caller can skip the reconfigure call in that
+    /// case.
+    ///
+    /// Offsets present in `current` but missing from this config are never
+    /// added to the returned `Config` (there are no settings here to apply
+    /// for them), but are flagged in the returned `Vec<Offset>` so the
+    /// caller can decide what to do about lines that dropped out of the
+    /// target configuration - e.g. release them, or leave them requested
+    /// as-is.
+    pub fn diff(&self, current: &SettingsMap) -> Result<(Config, Vec<Offset>)> {
+        let target = self.line_settings()?;
+        let mut diff = Config::new()?;
+
+        for (offset, settings) in target.iter() {
+            let offset = offset as Offset;
+
+            let changed = match current.get(offset) {
+                Some(existing) => {
+                    let mut changed = false;
+
+                    for kind in SETTING_KINDS {
+                        if settings.prop(kind)? != existing.prop(kind)? {
+                            changed = true;
+                            break;
+                        }
+                    }
+
+                    changed
+                }
+                None => true,
+            };
+
+            if changed {
+                diff.add_line_settings(&[offset], settings.settings_clone()?)?;
+            }
+        }
+
+        let target_offsets: std::collections::HashSet<Offset> =
+            target.iter().map(|(offset, _)| offset as Offset).collect();
+
+        let removed = current
+            .iter()
+            .map(|(offset, _)| offset as Offset)
+            .filter(|offset| !target_offsets.contains(offset))
+            .collect();
+
+        Ok((diff, removed))
+    }
 }
 
 impl Drop for Config {
@@ -2,6 +2,7 @@
 // SPDX-FileCopyrightText: 2022 Linaro Ltd.
 // SPDX-FileCopyrightText: 2022 Viresh Kumar <viresh.kumar@linaro.org>
 
+use std::fmt;
 use std::ops::Deref;
 use std::str;
 use std::time::Duration;
@@ -176,6 +177,284 @@ impl InfoRef {
             gpiod::gpiod_line_info_get_debounce_period_us(self.as_raw_ptr()) as u64
         })
     }
+
+    /// Compare this snapshot against `other`, typically a later snapshot of
+    /// the same line (e.g. `self` taken right before and `other` right after
+    /// a
+    /// [`InfoChangeKind::LineConfigChanged`](crate::line::InfoChangeKind::LineConfigChanged)
+    /// event), and report which properties differ, with the value each has
+    /// in `other`.
+    ///
+    /// Built on top of [`InfoRef::snapshot`]/[`LineInfoSnapshot::changes_from`],
+    /// so properties that fail to read are treated as a sentinel value rather
+    /// than propagating the error, the same convention
+    /// [`Settings`](crate::line::Settings)'s `PartialEq` uses - two sides
+    /// that both fail the same getter are considered unchanged on that
+    /// property.
+    pub fn diff(&self, other: &InfoRef) -> LineInfoChanges {
+        other.snapshot().changes_from(&self.snapshot())
+    }
+
+    /// Eagerly read every property into a plain, pointer-free snapshot.
+    ///
+    /// Unlike [`InfoRef::try_clone`], whose [`Info`] still wraps a
+    /// `gpiod_line_info` pointer (and is therefore `Send` but not `Sync`,
+    /// and cannot be serialized), the returned [`LineInfoSnapshot`] is made
+    /// up entirely of owned Rust values. It outlives the originating chip or
+    /// event, can be sent or shared freely between threads, and can be
+    /// stashed in a collection for later comparison.
+    ///
+    /// Properties that fail to read fall back to the same defaults
+    /// `Default` would give the respective field (e.g. a missing `name` or
+    /// `consumer` becomes `None`), rather than making the whole snapshot
+    /// fallible over what is usually just an absent optional string.
+    pub fn snapshot(&self) -> LineInfoSnapshot {
+        LineInfoSnapshot {
+            offset: self.offset(),
+            name: self.name().ok().map(str::to_owned),
+            consumer: self.consumer().ok().map(str::to_owned),
+            direction: self.direction().ok(),
+            active_low: self.is_active_low(),
+            bias: self.bias().ok().flatten(),
+            drive: self.drive().ok(),
+            edge_detection: self.edge_detection().ok().flatten(),
+            event_clock: self.event_clock().ok(),
+            used: self.is_used(),
+            debounced: self.is_debounced(),
+            debounce_period: self.debounce_period(),
+        }
+    }
+}
+
+/// (De)serializes a [`Duration`] as a microsecond count, matching the unit
+/// the kernel itself reports debounce periods in - used instead of serde's
+/// default `Duration` representation so a dumped snapshot reads naturally
+/// next to other `gpioinfo`-style tools.
+#[cfg(feature = "serde")]
+mod duration_micros {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(
+        period: &Duration,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        (period.as_micros() as u64).serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Duration, D::Error> {
+        Ok(Duration::from_micros(u64::deserialize(deserializer)?))
+    }
+}
+
+/// A fully owned, pointer-free snapshot of a line's properties, as produced
+/// by [`InfoRef::snapshot`].
+///
+/// `direction`, `bias`, `drive`, `edge_detection` and `event_clock` are
+/// `None` if the underlying C accessor failed - see [`InfoRef::snapshot`].
+///
+/// With the `serde` cargo feature enabled, this also implements
+/// `Serialize`/`Deserialize` - `direction`/`bias`/`drive`/`edge_detection`/
+/// `event_clock` as stable string tags and `debounce_period` as a
+/// microsecond count - so a chip's line state can be dumped to JSON for
+/// logging, remote diagnostics, or golden-file tests, and read back for
+/// comparison across reboots or between hardware. Deserializing only ever
+/// produces a plain [`LineInfoSnapshot`], never a live `gpiod_line_info`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LineInfoSnapshot {
+    pub offset: Offset,
+    pub name: Option<String>,
+    pub consumer: Option<String>,
+    pub direction: Option<Direction>,
+    pub active_low: bool,
+    pub bias: Option<Bias>,
+    pub drive: Option<Drive>,
+    pub edge_detection: Option<Edge>,
+    pub event_clock: Option<EventClock>,
+    pub used: bool,
+    pub debounced: bool,
+    #[cfg_attr(feature = "serde", serde(with = "duration_micros"))]
+    pub debounce_period: Duration,
+}
+
+impl LineInfoSnapshot {
+    /// Compare this snapshot against `previous`, typically an earlier
+    /// snapshot of the same line, and report which properties differ.
+    ///
+    /// Unlike [`InfoRef::diff`], which compares two live [`InfoRef`]s and
+    /// must tolerate either side failing a getter, both sides here are
+    /// already-read owned values, so every field is a plain equality check.
+    /// Useful for a monitoring daemon that keeps a history of
+    /// [`LineInfoSnapshot`]s (e.g. one per
+    /// [`InfoChangeKind::LineConfigChanged`](crate::line::InfoChangeKind::LineConfigChanged)
+    /// event) instead of re-reading the kernel for a "before" state.
+    pub fn changes_from(&self, previous: &LineInfoSnapshot) -> LineInfoChanges {
+        let mut changes = LineInfoChanges::default();
+
+        if self.used != previous.used {
+            changes.used = Some(self.used);
+        }
+        if self.consumer != previous.consumer {
+            changes.consumer = Some(self.consumer.clone());
+        }
+        if self.direction != previous.direction {
+            changes.direction = Some(self.direction);
+        }
+        if self.active_low != previous.active_low {
+            changes.active_low = Some(self.active_low);
+        }
+        if self.bias != previous.bias {
+            changes.bias = Some(self.bias);
+        }
+        if self.drive != previous.drive {
+            changes.drive = Some(self.drive);
+        }
+        if self.edge_detection != previous.edge_detection {
+            changes.edge_detection = Some(self.edge_detection);
+        }
+        if self.event_clock != previous.event_clock {
+            changes.event_clock = Some(self.event_clock);
+        }
+        if self.debounced != previous.debounced {
+            changes.debounced = Some(self.debounced);
+        }
+        if self.debounce_period != previous.debounce_period {
+            changes.debounce_period = Some(self.debounce_period);
+        }
+
+        changes
+    }
+}
+
+/// Renders one line in the familiar `gpioinfo` layout: offset, quoted name
+/// (or `unnamed`), quoted consumer (or `unused`), direction, and any active
+/// attributes - `used`, bias, drive other than push-pull, edge detection,
+/// event clock other than monotonic, debounce period - as trailing
+/// human-readable tokens.
+impl fmt::Display for LineInfoSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {:>3}:", self.offset)?;
+
+        match &self.name {
+            Some(name) => write!(f, " {:>11}", format!("\"{name}\""))?,
+            None => write!(f, " {:>11}", "unnamed")?,
+        }
+
+        match &self.consumer {
+            Some(consumer) => write!(f, " {:>11}", format!("\"{consumer}\""))?,
+            None => write!(f, " {:>11}", "unused")?,
+        }
+
+        write!(
+            f,
+            " {:>6}",
+            match self.direction {
+                Some(Direction::Input) => "input",
+                Some(Direction::Output) => "output",
+                Some(Direction::AsIs) | None => "as-is",
+            }
+        )?;
+
+        write!(
+            f,
+            " {}",
+            if self.active_low {
+                "active-low"
+            } else {
+                "active-high"
+            }
+        )?;
+
+        let mut attrs = Vec::new();
+
+        if self.used {
+            attrs.push("used".to_owned());
+        }
+        if let Some(bias) = self.bias {
+            attrs.push(
+                match bias {
+                    Bias::Disabled => "bias=disabled",
+                    Bias::PullUp => "bias=pull-up",
+                    Bias::PullDown => "bias=pull-down",
+                }
+                .to_owned(),
+            );
+        }
+        if let Some(drive) = self.drive.filter(|drive| *drive != Drive::PushPull) {
+            attrs.push(
+                match drive {
+                    Drive::PushPull => "drive=push-pull",
+                    Drive::OpenDrain => "drive=open-drain",
+                    Drive::OpenSource => "drive=open-source",
+                }
+                .to_owned(),
+            );
+        }
+        if let Some(edge) = self.edge_detection {
+            attrs.push(
+                match edge {
+                    Edge::Rising => "edge=rising",
+                    Edge::Falling => "edge=falling",
+                    Edge::Both => "edge=both",
+                }
+                .to_owned(),
+            );
+        }
+        if let Some(clock) = self
+            .event_clock
+            .filter(|clock| *clock != EventClock::Monotonic)
+        {
+            attrs.push(format!(
+                "clock={}",
+                match clock {
+                    EventClock::Monotonic => "monotonic",
+                    EventClock::Realtime => "realtime",
+                    EventClock::HTE => "hte",
+                }
+            ));
+        }
+        if self.debounced {
+            attrs.push(format!("debounce={:?}", self.debounce_period));
+        }
+
+        if !attrs.is_empty() {
+            write!(f, " [{}]", attrs.join(" "))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The result of [`LineInfoSnapshot::changes_from`] and [`InfoRef::diff`]:
+/// which properties changed between two snapshots of the same line, and the
+/// value each has in the later (or, for [`InfoRef::diff`], the `other`)
+/// snapshot.
+///
+/// Every field is `None` if that property is unchanged. A freshly built
+/// `LineInfoChanges::default()` therefore represents "no change".
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LineInfoChanges {
+    pub used: Option<bool>,
+    pub consumer: Option<Option<String>>,
+    pub direction: Option<Option<Direction>>,
+    pub active_low: Option<bool>,
+    pub bias: Option<Option<Bias>>,
+    pub drive: Option<Option<Drive>>,
+    pub edge_detection: Option<Option<Edge>>,
+    pub event_clock: Option<Option<EventClock>>,
+    pub debounced: Option<bool>,
+    pub debounce_period: Option<Duration>,
+}
+
+impl LineInfoChanges {
+    /// Returns true if no property differed, i.e. `self == LineInfoChanges::default()`.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
 }
 
 /// Line info
@@ -5,9 +5,11 @@
 #[cfg(feature = "v2_1")]
 use std::ffi::CStr;
 use std::os::unix::prelude::AsRawFd;
+use std::path::Path;
 use std::time::Duration;
 
 use super::{
+    chip::Chip,
     gpiod,
     line::{self, Offset, Value, ValueMap},
     request, Error, OperationType, Result,
@@ -22,8 +24,13 @@ pub struct Request {
 }
 
 // SAFETY: Request models a wrapper around an owned gpiod_line_request and may
-// be safely sent to other threads.
+// be safely sent to other threads. It's also safe to share behind a reference:
+// the underlying gpiod_line_request is never freed before Request drops. The
+// mutating operations reachable through a shared reference are those of
+// SharedRequest, which serializes reconfiguration with a mutex and leaves
+// value sets to the kernel, which accepts them concurrently on one fd.
 unsafe impl Send for Request {}
+unsafe impl Sync for Request {}
 
 impl Request {
     /// Request a set of lines for exclusive usage.
@@ -36,6 +43,54 @@ impl Request {
         Ok(Self { request })
     }
 
+    /// Open `chip_path`, request `offsets` as input lines consumed by
+    /// `consumer`, and return the request.
+    ///
+    /// This is a convenience wrapper around building an input
+    /// [`line::Settings`], a [`line::Config`] and a [`request::Config`] with
+    /// the consumer set, then calling [`Chip::open`] and
+    /// [`Chip::request_lines`] - the common case for a one-shot read. For
+    /// anything beyond plain input lines, build the config objects directly.
+    pub fn get<P: AsRef<Path>>(chip_path: &P, offsets: &[Offset], consumer: &str) -> Result<Self> {
+        let mut settings = line::Settings::new()?;
+        settings.set_direction(line::Direction::Input)?;
+
+        let mut lconfig = line::Config::new()?;
+        lconfig.add_line_settings(offsets, settings)?;
+
+        let mut rconfig = request::Config::new()?;
+        rconfig.set_consumer(consumer)?;
+
+        Chip::open(chip_path)?.request_lines(Some(&rconfig), &lconfig)
+    }
+
+    /// Open `chip_path`, request the offsets in `values` as output lines set
+    /// to the given values and consumed by `consumer`, and return the
+    /// request.
+    ///
+    /// See [`Request::get`] for the input counterpart.
+    pub fn set<P: AsRef<Path>>(
+        chip_path: &P,
+        values: &[(Offset, Value)],
+        consumer: &str,
+    ) -> Result<Self> {
+        let offsets: Vec<Offset> = values.iter().map(|(offset, _)| *offset).collect();
+        let output_values: Vec<Value> = values.iter().map(|(_, value)| *value).collect();
+
+        let mut settings = line::Settings::new()?;
+        settings.set_direction(line::Direction::Output)?;
+
+        let mut lconfig = line::Config::new()?;
+        lconfig
+            .add_line_settings(&offsets, settings)?
+            .set_output_values(&output_values)?;
+
+        let mut rconfig = request::Config::new()?;
+        rconfig.set_consumer(consumer)?;
+
+        Chip::open(chip_path)?.request_lines(Some(&rconfig), &lconfig)
+    }
+
     /// Get the name of the chip this request was made on.
     #[cfg(feature = "v2_1")]
     pub fn chip_name(&self) -> Result<&str> {
@@ -193,6 +248,21 @@ impl Request {
         }
     }
 
+    /// Read the current values of all requested lines and write back their
+    /// inverse.
+    pub fn toggle_values(&mut self) -> Result<&mut Self> {
+        let offsets = self.offsets();
+        let values = self.values_subset(&offsets)?;
+
+        let mut toggled = ValueMap::new();
+        for offset in offsets {
+            let value = values.get(offset).ok_or(Error::InvalidArguments)?;
+            toggled.insert(offset, value.toggled());
+        }
+
+        self.set_values_subset(toggled)
+    }
+
     /// Update the configuration of lines associated with the line request.
     pub fn reconfigure_lines(&mut self, lconfig: &line::Config) -> Result<&mut Self> {
         // SAFETY: `gpiod_line_request` is guaranteed to be valid here.
@@ -230,6 +300,19 @@ impl Request {
         }
     }
 
+    /// Check whether edge events are currently queued for this request,
+    /// without blocking.
+    ///
+    /// This is the non-blocking counterpart to
+    /// [`wait_edge_events`](Request::wait_edge_events) - a thin wrapper
+    /// around `wait_edge_events(Some(Duration::ZERO))` - meant for reactors
+    /// that register [`Request`]'s fd (via [`AsRawFd`]) with `epoll`/`mio`
+    /// directly and just need to confirm readiness before calling
+    /// [`read_edge_events`](Request::read_edge_events).
+    pub fn poll_edge_events(&self) -> Result<bool> {
+        self.wait_edge_events(Some(Duration::ZERO))
+    }
+
     /// Get a number of edge events from a line request.
     ///
     /// This function will block if no event was queued for the line.
@@ -239,6 +322,61 @@ impl Request {
     ) -> Result<request::Events<'a>> {
         buffer.read_edge_events(self)
     }
+
+    /// Get a self-contained, blocking iterator over this request's edge
+    /// events.
+    ///
+    /// Unlike [`Request::read_edge_events`], this owns its buffer
+    /// internally (sized with the same semantics as
+    /// [`request::Buffer::new`]) and transparently blocks on
+    /// `wait_edge_events` to refill it, so simple event loops don't need to
+    /// manage a buffer by hand.
+    pub fn edge_events(&self, capacity: usize) -> Result<request::EdgeEventIter<'_>> {
+        request::EdgeEventIter::new(self, capacity)
+    }
+
+    /// Get an async [`Stream`](futures_core::Stream) of edge events for this request.
+    ///
+    /// Requires the `tokio` cargo feature. `capacity` is forwarded to the
+    /// stream's internal [`request::Buffer`].
+    #[cfg(feature = "tokio")]
+    pub fn edge_events_stream(&self, capacity: usize) -> Result<request::EdgeEventStream<'_>> {
+        request::EdgeEventStream::new(self, capacity)
+    }
+
+    /// Await until at least one edge event is available, then drain it into
+    /// `buffer`, without blocking the executor thread.
+    ///
+    /// Requires the `tokio` cargo feature. This is the async one-shot
+    /// counterpart to [`Request::read_edge_events`].
+    #[cfg(feature = "tokio")]
+    pub async fn read_edge_events_async<'a>(
+        &self,
+        buffer: &'a mut request::Buffer,
+    ) -> Result<request::Events<'a>> {
+        request::read_edge_events_async(self, buffer).await
+    }
+
+    /// Await until at least one edge event is available, then drain it into
+    /// `buffer`, without blocking the executor thread.
+    ///
+    /// Requires the `reactor` cargo feature - a small built-in `epoll`-based
+    /// reactor, for embedders that want async edge monitoring without a
+    /// `tokio` dependency. Mutually exclusive with the `tokio` feature's
+    /// method of the same name; enable whichever matches your executor.
+    #[cfg(feature = "reactor")]
+    pub async fn read_edge_events_async<'a>(
+        &self,
+        buffer: &'a mut request::Buffer,
+    ) -> Result<request::Events<'a>> {
+        request::read_edge_events_async(self, buffer).await
+    }
+
+    /// Wrap this request in a [`request::SharedRequest`] for use from
+    /// multiple threads.
+    pub fn shared(self) -> request::SharedRequest {
+        request::SharedRequest::new(self)
+    }
 }
 
 impl AsRawFd for Request {
@@ -2,6 +2,7 @@
 // SPDX-FileCopyrightText: 2022 Linaro Ltd.
 // SPDX-FileCopyrightText: 2022 Viresh Kumar <viresh.kumar@linaro.org>
 
+use std::hash::{Hash, Hasher};
 use std::time::Duration;
 
 use super::{
@@ -20,11 +21,44 @@ use super::{
 /// a mutator fails and simply uses the sane default appropriate for given
 /// property.
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug)]
 pub struct Settings {
     pub(crate) settings: *mut gpiod::gpiod_line_settings,
 }
 
+// Two `Settings` objects are equal if their property values are equal, not if
+// they happen to wrap the same pointer - two independently built objects (or
+// a clone and its source) with identical properties should compare equal.
+// Any getter `Err` is treated as a sentinel value, so two objects that both
+// fail the same getter still compare equal on that property.
+impl PartialEq for Settings {
+    fn eq(&self, other: &Self) -> bool {
+        self.direction().ok() == other.direction().ok()
+            && self.edge_detection().ok() == other.edge_detection().ok()
+            && self.bias().ok() == other.bias().ok()
+            && self.drive().ok() == other.drive().ok()
+            && self.active_low() == other.active_low()
+            && self.debounce_period().ok() == other.debounce_period().ok()
+            && self.event_clock().ok() == other.event_clock().ok()
+            && self.output_value().ok() == other.output_value().ok()
+    }
+}
+
+impl Eq for Settings {}
+
+impl Hash for Settings {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.direction().ok().hash(state);
+        self.edge_detection().ok().hash(state);
+        self.bias().ok().hash(state);
+        self.drive().ok().hash(state);
+        self.active_low().hash(state);
+        self.debounce_period().ok().hash(state);
+        self.event_clock().ok().hash(state);
+        self.output_value().ok().hash(state);
+    }
+}
+
 impl Settings {
     /// Create a new line settings object.
     pub fn new() -> Result<Self> {
@@ -139,6 +173,11 @@ impl Settings {
     }
 
     /// Get the edge event detection setting.
+    ///
+    /// Events detected under whatever `Edge`/`EventClock` these settings
+    /// configure can be consumed without a dedicated blocking thread via
+    /// [`Request::edge_events_stream`](crate::request::Request::edge_events_stream)
+    /// (requires the `tokio` cargo feature).
     pub fn edge_detection(&self) -> Result<Option<Edge>> {
         // SAFETY: `gpiod_line_settings` is guaranteed to be valid here.
         Edge::new(unsafe { gpiod::gpiod_line_settings_get_edge_detection(self.settings) })
@@ -225,6 +264,12 @@ impl Settings {
     }
 
     /// Set the event clock setting.
+    ///
+    /// This only records the clock in the settings object; the kernel is not
+    /// consulted here, so [EventClock::HTE] is accepted even for a controller
+    /// without a hardware timestamping engine. The kernel rejects it later,
+    /// when the settings are used to request or reconfigure lines, and that
+    /// call fails with [`Error::OperationFailed`](super::Error::OperationFailed).
     pub fn set_event_clock(&mut self, clock: EventClock) -> Result<&mut Self> {
         // SAFETY: `gpiod_line_settings` is guaranteed to be valid here.
         let ret = unsafe {
@@ -263,6 +308,41 @@ impl Settings {
         }
     }
 
+    /// Check these settings for combinations that are locally known to be
+    /// inconsistent, before they are used to request or reconfigure lines.
+    ///
+    /// libgpiod has no API to ask a controller ahead of time which event
+    /// clocks (in particular [`EventClock::HTE`]) or debounce behavior it
+    /// supports - the kernel is the sole authority on that, and the only way
+    /// to find out is to attempt the request/reconfigure and check for
+    /// `ENODEV`/`EOPNOTSUPP` on the resulting
+    /// [`Error::OperationFailed`](super::Error::OperationFailed). What this
+    /// *can* check without touching the kernel is internal consistency: a
+    /// debounce period only has an effect when edge detection is enabled.
+    /// Returns [`Error::InvalidSettings`](super::Error::InvalidSettings)
+    /// naming the offending property rather than letting it be silently
+    /// ignored.
+    pub fn validate(&self) -> Result<()> {
+        if self.debounce_period()? != Duration::ZERO && self.edge_detection()?.is_none() {
+            return Err(Error::InvalidSettings(
+                "debounce_period has no effect without edge_detection",
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Snapshot this object's properties into a serializable
+    /// [`LineProfile`](crate::line::LineProfile), e.g. to save to a
+    /// TOML/JSON config file.
+    ///
+    /// Requires the `serde` cargo feature. See also
+    /// `TryFrom<&LineProfile>`, its inverse.
+    #[cfg(feature = "serde")]
+    pub fn to_data(&self) -> Result<crate::line::LineProfile> {
+        crate::line::LineProfile::from_settings(self)
+    }
+
     /// Get the output value, 0 or 1.
     pub fn output_value(&self) -> Result<Value> {
         // SAFETY: `gpiod_line_settings` is guaranteed to be valid here.
@@ -0,0 +1,138 @@
+// SPDX-License-Identifier: Apache-2.0 OR BSD-3-Clause
+// SPDX-FileCopyrightText: 2022 Linaro Ltd.
+// SPDX-FileCopyrightText: 2022 Viresh Kumar <viresh.kumar@linaro.org>
+
+use std::collections::VecDeque;
+use std::path::Path;
+use std::time::Duration;
+
+use intmap::IntMap;
+
+use super::{
+    chip::Chip,
+    line::{self, Edge, Offset},
+    request::{self, EdgeEventData, Request},
+    Result,
+};
+
+/// Per-line watch configuration for [`Monitor`].
+#[derive(Debug, Clone, Copy)]
+pub struct LineWatch {
+    /// The line to watch.
+    pub offset: Offset,
+    /// The edge(s) to detect on this line.
+    pub edge: Edge,
+    /// The debounce period to apply to this line.
+    pub debounce_period: Duration,
+}
+
+/// A multi-line edge monitor built on top of [`Request`].
+///
+/// Requests a set of lines, each with its own [`Edge`]/debounce-period
+/// configuration (reusing the same reconfigure machinery exercised by the
+/// `debounce` and `edge` tests), and turns the low-level
+/// `wait_edge_events`/`read_edge_events` pair into a single, merged,
+/// time-ordered stream of [`EdgeEventData`] tagged by the offset they
+/// occurred on - `EdgeEventData::line_offset` already carries that tag.
+///
+/// Optionally keeps a bounded per-line ring buffer of recent events (sized
+/// by `history_capacity`), so a caller can ask e.g. "how many rising edges on
+/// offset 3 in the last N events" without re-implementing that bookkeeping.
+pub struct Monitor {
+    request: Request,
+    buffer: request::Buffer,
+    history_capacity: usize,
+    history: IntMap<Offset, VecDeque<EdgeEventData>>,
+}
+
+impl Monitor {
+    /// Open `chip_path`, request `watches` with their per-line edge-detection
+    /// and debounce settings, and start monitoring.
+    ///
+    /// `history_capacity` bounds how many recent events are retained per
+    /// line; 0 disables history tracking entirely. The internal edge-event
+    /// buffer reuses the library's normal buffer-sizing rules (see
+    /// [`request::Buffer::new`]).
+    pub fn new<P: AsRef<Path>>(
+        chip_path: &P,
+        watches: &[LineWatch],
+        consumer: &str,
+        history_capacity: usize,
+    ) -> Result<Self> {
+        let mut lconfig = line::Config::new()?;
+
+        for watch in watches {
+            let mut settings = line::Settings::new()?;
+            settings
+                .set_direction(line::Direction::Input)?
+                .set_edge_detection(Some(watch.edge))?
+                .set_debounce_period(watch.debounce_period);
+            lconfig.add_line_settings(&[watch.offset], settings)?;
+        }
+
+        let mut rconfig = request::Config::new()?;
+        rconfig.set_consumer(consumer)?;
+
+        let request = Chip::open(chip_path)?.request_lines(Some(&rconfig), &lconfig)?;
+        let buffer = request::Buffer::new(rconfig.event_buffer_size())?;
+
+        Ok(Self {
+            request,
+            buffer,
+            history_capacity,
+            history: IntMap::new(),
+        })
+    }
+
+    /// Block until edge events are queued, then return the time-ordered
+    /// batch of events delivered by this wakeup, recording each into its
+    /// line's history.
+    pub fn wait(&mut self) -> Result<Vec<EdgeEventData>> {
+        self.request.wait_edge_events(None)?;
+
+        let mut events = Vec::new();
+
+        for event in self.buffer.read_edge_events(&self.request)? {
+            let data = event?.snapshot()?;
+            self.record(data);
+            events.push(data);
+        }
+
+        Ok(events)
+    }
+
+    /// Append `data` to its line's history, evicting the oldest entry once
+    /// `history_capacity` is exceeded.
+    fn record(&mut self, data: EdgeEventData) {
+        if self.history_capacity == 0 {
+            return;
+        }
+
+        let queue = match self.history.get_mut(data.line_offset) {
+            Some(queue) => queue,
+            None => {
+                self.history.insert(data.line_offset, VecDeque::new());
+                self.history.get_mut(data.line_offset).unwrap()
+            }
+        };
+
+        if queue.len() == self.history_capacity {
+            queue.pop_front();
+        }
+        queue.push_back(data);
+    }
+
+    /// Get the recent event history recorded for `offset`, oldest first.
+    ///
+    /// Empty if `history_capacity` is 0 or no events have been seen yet for
+    /// this offset.
+    pub fn history(&self, offset: Offset) -> impl Iterator<Item = &EdgeEventData> {
+        self.history.get(offset).into_iter().flatten()
+    }
+
+    /// Get the underlying [`Request`], e.g. to read or set line values
+    /// directly.
+    pub fn request(&self) -> &Request {
+        &self.request
+    }
+}
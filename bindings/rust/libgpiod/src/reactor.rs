@@ -0,0 +1,189 @@
+// SPDX-License-Identifier: Apache-2.0 OR BSD-3-Clause
+// SPDX-FileCopyrightText: 2022 Linaro Ltd.
+// SPDX-FileCopyrightText: 2022 Viresh Kumar <viresh.kumar@linaro.org>
+
+//! A minimal `epoll`-based reactor backing [`request::Request::read_edge_events_async`],
+//! available behind the `reactor` cargo feature as an alternative to the
+//! `tokio`-backed async API for embedders that don't want a full runtime
+//! dependency.
+//!
+//! Mirrors the single-waker-array design used by embassy's PIO driver: one
+//! shared `epoll` instance and one dedicated reactor thread serve every
+//! registered fd, and each fd's [`Waker`](std::task::Waker) lives in a flat,
+//! token-indexed slab rather than a per-fd map, so waking a ready fd is an
+//! O(1) array access instead of a hash lookup.
+
+use std::future::poll_fn;
+use std::os::unix::io::RawFd;
+use std::sync::{Mutex, OnceLock};
+use std::task::Poll;
+use std::time::Duration;
+
+use futures_util::task::AtomicWaker;
+
+use super::{
+    event_buffer::{Buffer, Events},
+    fd::NonBlockingGuard,
+    line_request::Request,
+    Error, OperationType, Result,
+};
+
+/// Upper bound on the number of fds this reactor can watch at once.
+const MAX_TOKENS: usize = 1024;
+
+struct Reactor {
+    epoll_fd: RawFd,
+    wakers: Vec<AtomicWaker>,
+    free_tokens: Mutex<Vec<usize>>,
+}
+
+// SAFETY: all of `Reactor`'s fields are either plain atomics (`AtomicWaker`)
+// or guarded by a `Mutex`; `epoll_fd` is only ever passed to thread-safe
+// `libc::epoll_*` calls.
+unsafe impl Send for Reactor {}
+unsafe impl Sync for Reactor {}
+
+impl Reactor {
+    fn get() -> &'static Reactor {
+        static REACTOR: OnceLock<Reactor> = OnceLock::new();
+        REACTOR.get_or_init(Reactor::spawn)
+    }
+
+    fn spawn() -> Self {
+        // SAFETY: `epoll_create1` with no flags just asks the kernel for a
+        // fresh epoll instance.
+        let epoll_fd = unsafe { libc::epoll_create1(0) };
+        assert!(
+            epoll_fd >= 0,
+            "libgpiod reactor: epoll_create1 failed: {}",
+            std::io::Error::last_os_error()
+        );
+
+        let reactor = Self {
+            epoll_fd,
+            wakers: (0..MAX_TOKENS).map(|_| AtomicWaker::new()).collect(),
+            free_tokens: Mutex::new((0..MAX_TOKENS).rev().collect()),
+        };
+
+        std::thread::Builder::new()
+            .name("libgpiod-reactor".to_owned())
+            .spawn(move || Reactor::poll_loop(epoll_fd))
+            .expect("libgpiod reactor: failed to spawn reactor thread");
+
+        reactor
+    }
+
+    /// Runs for the life of the process on a dedicated thread, waking the
+    /// `AtomicWaker` of every token that becomes readable.
+    fn poll_loop(epoll_fd: RawFd) -> ! {
+        let mut events = [libc::epoll_event { events: 0, u64: 0 }; 64];
+
+        loop {
+            // SAFETY: `events` is a valid, appropriately sized buffer for the
+            // duration of the call.
+            let n = unsafe {
+                libc::epoll_wait(epoll_fd, events.as_mut_ptr(), events.len() as i32, -1)
+            };
+
+            if n < 0 {
+                // Interrupted by a signal - nothing to do but retry.
+                continue;
+            }
+
+            let reactor = Reactor::get();
+            for event in &events[..n as usize] {
+                reactor.wakers[event.u64 as usize].wake();
+            }
+        }
+    }
+
+    /// Arm `fd` edge-triggered (`EPOLLET`) for readability and return the
+    /// token its `AtomicWaker` is kept at.
+    fn register(&self, fd: RawFd) -> Result<usize> {
+        let token = self
+            .free_tokens
+            .lock()
+            .unwrap()
+            .pop()
+            .ok_or(Error::ReactorTokensExhausted(MAX_TOKENS))?;
+
+        let mut event = libc::epoll_event {
+            events: (libc::EPOLLIN | libc::EPOLLET) as u32,
+            u64: token as u64,
+        };
+
+        // SAFETY: `self.epoll_fd` is a live epoll instance; `fd` is a valid,
+        // open file descriptor that outlives this registration; `event` is
+        // only read for the duration of the call.
+        let ret = unsafe { libc::epoll_ctl(self.epoll_fd, libc::EPOLL_CTL_ADD, fd, &mut event) };
+        if ret == -1 {
+            self.free_tokens.lock().unwrap().push(token);
+            return Err(Error::OperationFailed(
+                OperationType::ReactorEpollCtl,
+                errno::errno(),
+            ));
+        }
+
+        Ok(token)
+    }
+
+    /// Disarm `fd` and return its token to the free list.
+    fn unregister(&self, fd: RawFd, token: usize) {
+        // SAFETY: see `register`. A failure here just leaves a harmless
+        // stale registration behind - there is nothing more to do with it in
+        // a `Drop` impl.
+        unsafe { libc::epoll_ctl(self.epoll_fd, libc::EPOLL_CTL_DEL, fd, std::ptr::null_mut()) };
+        self.free_tokens.lock().unwrap().push(token);
+    }
+}
+
+/// Unregisters a reactor token when dropped, including on cancellation (the
+/// future below is dropped mid-`.await`).
+struct TokenGuard {
+    fd: RawFd,
+    token: usize,
+}
+
+impl Drop for TokenGuard {
+    fn drop(&mut self) {
+        Reactor::get().unregister(self.fd, self.token);
+    }
+}
+
+/// Await until `request`'s fd has at least one edge event queued, then read
+/// as many as fit into `buffer`.
+///
+/// Backed by the module-level `epoll` reactor instead of an async runtime's
+/// own I/O driver, so this works without a `tokio`/`async-std` dependency.
+/// This is a one-shot read, not a drain: if the kernel has more events
+/// queued than `buffer`'s capacity, only one buffer's worth is returned and
+/// the rest stay queued for the next call to pick up (the fd is armed
+/// edge-triggered, but a fresh registration on the next call tends to
+/// re-fire immediately on a still-readable fd, so no event is lost - it's
+/// just returned on a later call instead of this one).
+pub async fn read_edge_events_async<'a>(
+    request: &Request,
+    buffer: &'a mut Buffer,
+) -> Result<Events<'a>> {
+    use std::os::unix::io::AsRawFd;
+
+    let _nonblocking = NonBlockingGuard::new(request.as_raw_fd())?;
+    let token = Reactor::get().register(request.as_raw_fd())?;
+    let _guard = TokenGuard {
+        fd: request.as_raw_fd(),
+        token,
+    };
+
+    poll_fn(|cx| {
+        Reactor::get().wakers[token].register(cx.waker());
+
+        match request.wait_edge_events(Some(Duration::ZERO)) {
+            Ok(true) => Poll::Ready(Ok(())),
+            Ok(false) => Poll::Pending,
+            Err(err) => Poll::Ready(Err(err)),
+        }
+    })
+    .await?;
+
+    buffer.read_edge_events(request)
+}
@@ -0,0 +1,154 @@
+// SPDX-License-Identifier: Apache-2.0 OR BSD-3-Clause
+// SPDX-FileCopyrightText: 2022 Linaro Ltd.
+// SPDX-FileCopyrightText: 2022 Viresh Kumar <viresh.kumar@linaro.org>
+
+use std::os::unix::io::AsRawFd;
+use std::time::Duration;
+
+use super::{
+    request::{Buffer, EdgeEventData, Request},
+    Error, OperationType, Result,
+};
+
+/// A stable handle identifying one of a [`Selector`]'s member requests.
+///
+/// Tokens stay valid for the lifetime of the request they were returned for
+/// by [`Selector::add`], regardless of which other requests are added to or
+/// removed from the selector afterwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Token(usize);
+
+/// Waits on many line requests - possibly spanning different chips - with a
+/// single `poll(2)` call.
+///
+/// Each member [`Request`] only supports being waited on individually via
+/// [`wait_edge_events`](Request::wait_edge_events); a daemon supervising
+/// dozens of lines across multiple chips would otherwise need one blocking
+/// thread per request. `Selector` instead builds a single pollfd set over
+/// every member's fd (via [`AsRawFd`]), so waiting on all of them costs one
+/// syscall.
+pub struct Selector {
+    requests: Vec<(Token, Request)>,
+    next_token: usize,
+}
+
+impl Selector {
+    /// Create an empty selector.
+    pub fn new() -> Self {
+        Self {
+            requests: Vec::new(),
+            next_token: 0,
+        }
+    }
+
+    /// Add `request` to the set this selector waits on.
+    ///
+    /// Returns a [`Token`] identifying `request` in future
+    /// [`wait`](Selector::wait)/[`poll_next_event`](Selector::poll_next_event)
+    /// results.
+    pub fn add(&mut self, request: Request) -> Token {
+        let token = Token(self.next_token);
+        self.next_token += 1;
+        self.requests.push((token, request));
+        token
+    }
+
+    /// Remove and return the request identified by `token`, if still present.
+    pub fn remove(&mut self, token: Token) -> Option<Request> {
+        let index = self.requests.iter().position(|(t, _)| *t == token)?;
+        Some(self.requests.swap_remove(index).1)
+    }
+
+    /// Get a reference to the request identified by `token`, if still
+    /// present.
+    pub fn get(&self, token: Token) -> Option<&Request> {
+        self.requests
+            .iter()
+            .find(|(t, _)| *t == token)
+            .map(|(_, request)| request)
+    }
+
+    /// Wait up to `timeout` (blocking indefinitely if `None`) for edge events
+    /// to be queued on any member request, and return the tokens of every
+    /// request that became readable.
+    ///
+    /// Returns an empty `Vec` on timeout.
+    pub fn wait(&self, timeout: Option<Duration>) -> Result<Vec<Token>> {
+        let mut pollfds: Vec<libc::pollfd> = self
+            .requests
+            .iter()
+            .map(|(_, request)| libc::pollfd {
+                fd: request.as_raw_fd(),
+                events: libc::POLLIN,
+                revents: 0,
+            })
+            .collect();
+
+        let timeout_ms = match timeout {
+            Some(timeout) => timeout.as_millis().try_into().unwrap_or(i32::MAX),
+            None => -1,
+        };
+
+        // SAFETY: `pollfds` is a valid array of `self.requests.len()`
+        // `pollfd`s for the duration of this call.
+        let ret = unsafe {
+            libc::poll(
+                pollfds.as_mut_ptr(),
+                pollfds.len() as libc::nfds_t,
+                timeout_ms,
+            )
+        };
+
+        if ret == -1 {
+            return Err(Error::OperationFailed(
+                OperationType::SelectorPoll,
+                errno::errno(),
+            ));
+        }
+
+        Ok(self
+            .requests
+            .iter()
+            .zip(pollfds.iter())
+            .filter(|(_, pollfd)| pollfd.revents & libc::POLLIN != 0)
+            .map(|((token, _), _)| *token)
+            .collect())
+    }
+
+    /// Wait up to `timeout` for edge events on any member request, and read
+    /// the events queued on the first ready request (in the order
+    /// [`wait`](Selector::wait) reports them) into `buffer`.
+    ///
+    /// A convenience over [`wait`](Selector::wait) +
+    /// [`Request::read_edge_events`] for callers that just want to react to
+    /// one request at a time without writing their own dispatch loop. Reads
+    /// up to `buffer`'s capacity in one call rather than a single event;
+    /// events beyond that capacity stay queued in the kernel for the next
+    /// call. Returns `None` on timeout.
+    pub fn poll_next_event(
+        &self,
+        timeout: Option<Duration>,
+        buffer: &mut Buffer,
+    ) -> Result<Option<(Token, Vec<EdgeEventData>)>> {
+        let Some(token) = self.wait(timeout)?.into_iter().next() else {
+            return Ok(None);
+        };
+
+        let request = self
+            .get(token)
+            .expect("token returned by wait() must still be present in the selector");
+
+        let events = request
+            .read_edge_events(buffer)?
+            .map(|event| event.and_then(|event| event.snapshot()))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Some((token, events)))
+    }
+}
+
+impl Default for Selector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
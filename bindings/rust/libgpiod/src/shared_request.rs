@@ -0,0 +1,173 @@
+// SPDX-License-Identifier: Apache-2.0 OR BSD-3-Clause
+// SPDX-FileCopyrightText: 2022 Linaro Ltd.
+// SPDX-FileCopyrightText: 2022 Viresh Kumar <viresh.kumar@linaro.org>
+
+use std::os::unix::prelude::AsRawFd;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use super::{
+    gpiod,
+    line::{self, Offset, Value, ValueMap},
+    request::{self, Request},
+    Error, OperationType, Result,
+};
+
+/// A thread-shareable handle to a [`Request`].
+///
+/// Wraps the request in an [`Arc`] so clones can be handed to multiple
+/// threads, and exposes `&self` methods for the operations the kernel already
+/// allows to run concurrently on one request fd: value gets/sets and
+/// edge-event waiting/reading. Only `reconfigure_lines` - the one operation
+/// the kernel does not allow to race with itself - is internally serialized
+/// with a [`Mutex`], and only against other `reconfigure_lines` calls.
+///
+/// This covers the common "one thread watches inputs, another drives
+/// outputs" split without callers having to wrap the whole request in their
+/// own mutex (which would needlessly serialize independent get/set/wait
+/// calls) or reason about the raw-pointer safety invariants themselves.
+///
+/// # Concurrency guarantees
+///
+/// - Value gets ([`value`](SharedRequest::value),
+///   [`values`](SharedRequest::values),
+///   [`values_subset`](SharedRequest::values_subset)) and value sets
+///   ([`set_value`](SharedRequest::set_value),
+///   [`set_values_subset`](SharedRequest::set_values_subset)) may run
+///   concurrently with each other and with edge-event waiting/reading, on any
+///   combination of threads - the kernel uAPI treats these as independent
+///   ioctls on the same fd.
+/// - Edge-event waiting ([`wait_edge_events`](SharedRequest::wait_edge_events))
+///   and reading ([`read_edge_events`](SharedRequest::read_edge_events)) are
+///   meant to be driven from a single reader thread; nothing here
+///   synchronizes two threads both draining the same `request::Buffer`.
+/// - [`reconfigure_lines`](SharedRequest::reconfigure_lines) is serialized
+///   against itself via an internal mutex, since the kernel does not allow
+///   two reconfigurations to race.
+#[derive(Debug, Clone)]
+pub struct SharedRequest {
+    request: Arc<Request>,
+    // Guards `gpiod_line_request_reconfigure_lines` only; it is the one
+    // request operation the kernel does not allow to race with itself.
+    reconfigure_lock: Arc<Mutex<()>>,
+}
+
+impl SharedRequest {
+    /// Wrap a [`Request`] for sharing across threads.
+    pub fn new(request: Request) -> Self {
+        Self {
+            request: Arc::new(request),
+            reconfigure_lock: Arc::new(Mutex::new(())),
+        }
+    }
+
+    /// Get the value (0 or 1) of a single line associated with the request.
+    pub fn value(&self, offset: Offset) -> Result<Value> {
+        self.request.value(offset)
+    }
+
+    /// Get values of a subset of lines associated with the request.
+    pub fn values_subset(&self, offsets: &[Offset]) -> Result<ValueMap> {
+        self.request.values_subset(offsets)
+    }
+
+    /// Get values of all lines associated with the request.
+    pub fn values(&self) -> Result<ValueMap> {
+        self.request.values()
+    }
+
+    /// Set the value of a single line associated with the request.
+    pub fn set_value(&self, offset: Offset, value: Value) -> Result<()> {
+        // SAFETY: `gpiod_line_request` is guaranteed to be valid here. Value
+        // sets are safe to run concurrently with value gets and edge-event
+        // waits/reads on the same fd; they do not take `reconfigure_lock`.
+        let ret = unsafe {
+            gpiod::gpiod_line_request_set_value(self.request.request, offset, value.value())
+        };
+
+        if ret == -1 {
+            Err(Error::OperationFailed(
+                OperationType::LineRequestSetVal,
+                errno::errno(),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Set values of a subset of lines associated with the request.
+    pub fn set_values_subset(&self, map: ValueMap) -> Result<()> {
+        let mut offsets = Vec::new();
+        let mut values = Vec::new();
+
+        for (offset, value) in map {
+            offsets.push(offset as u32);
+            values.push(value.value());
+        }
+
+        // SAFETY: see `set_value`.
+        let ret = unsafe {
+            gpiod::gpiod_line_request_set_values_subset(
+                self.request.request,
+                offsets.len(),
+                offsets.as_ptr(),
+                values.as_ptr(),
+            )
+        };
+
+        if ret == -1 {
+            Err(Error::OperationFailed(
+                OperationType::LineRequestSetValSubset,
+                errno::errno(),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Wait for edge events on any of the lines associated with the request.
+    pub fn wait_edge_events(&self, timeout: Option<Duration>) -> Result<bool> {
+        self.request.wait_edge_events(timeout)
+    }
+
+    /// Get a number of edge events from the request.
+    ///
+    /// This function will block if no event was queued for the line.
+    pub fn read_edge_events<'a>(
+        &self,
+        buffer: &'a mut request::Buffer,
+    ) -> Result<request::Events<'a>> {
+        self.request.read_edge_events(buffer)
+    }
+
+    /// Update the configuration of lines associated with the line request.
+    ///
+    /// Serialized against other calls to `reconfigure_lines` on the same
+    /// shared request via an internal mutex - this is the one operation the
+    /// kernel does not allow to race with itself.
+    pub fn reconfigure_lines(&self, lconfig: &line::Config) -> Result<()> {
+        let _guard = self.reconfigure_lock.lock().unwrap();
+
+        // SAFETY: `gpiod_line_request` is guaranteed to be valid here, and
+        // `reconfigure_lock` prevents concurrent reconfiguration.
+        let ret = unsafe {
+            gpiod::gpiod_line_request_reconfigure_lines(self.request.request, lconfig.config)
+        };
+
+        if ret == -1 {
+            Err(Error::OperationFailed(
+                OperationType::LineRequestReconfigLines,
+                errno::errno(),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl AsRawFd for SharedRequest {
+    /// Get the file descriptor associated with the underlying request.
+    fn as_raw_fd(&self) -> i32 {
+        self.request.as_raw_fd()
+    }
+}
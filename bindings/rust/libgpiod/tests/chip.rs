@@ -93,5 +93,31 @@ mod chip {
                 )
             );
         }
+
+        #[test]
+        fn find_line_across_chips() {
+            use libgpiod::find_line;
+
+            let mut sim = Sim::new(Some(NGPIO), None, false).unwrap();
+            sim.set_line_name(0, "bank0-line").unwrap();
+
+            let bank = sim.add_bank().unwrap();
+            bank.set_num_lines(NGPIO).unwrap();
+            bank.set_line_name(3, "bank1-line").unwrap();
+
+            sim.enable().unwrap();
+
+            let (chip, offset) = find_line(&"/dev", "bank1-line").unwrap();
+            assert_eq!(offset, 3);
+            assert_eq!(
+                chip.path().unwrap(),
+                sim.banks()[1].dev_path().unwrap().to_str().unwrap()
+            );
+
+            assert_eq!(
+                find_line(&"/dev", "nonexistent-line").unwrap_err(),
+                ChipError::LineNotFound("nonexistent-line".to_owned())
+            );
+        }
     }
 }
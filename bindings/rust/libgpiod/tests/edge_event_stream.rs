@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: Apache-2.0 OR BSD-3-Clause
+// SPDX-FileCopyrightText: 2022 Linaro Ltd.
+// SPDX-FileCopyrightText: 2022 Viresh Kumar <viresh.kumar@linaro.org>
+
+mod common;
+
+#[cfg(feature = "tokio")]
+mod edge_event_stream {
+    use std::thread;
+    use std::time::Duration;
+
+    use futures_util::StreamExt;
+    use gpiosim_sys::Pull;
+
+    use crate::common::*;
+    use libgpiod::line::{Edge, EdgeKind};
+
+    const NGPIO: usize = 8;
+
+    // A readiness notification that carries more events than fit in a
+    // single `Buffer` must not leave any of them stuck: the stream has to
+    // keep draining the kernel queue before it re-arms readiness.
+    #[tokio::test]
+    async fn over_capacity() {
+        const GPIO: u32 = 4;
+
+        let mut config = TestConfig::new(NGPIO).unwrap();
+        config.lconfig_edge(None, Some(Edge::Both));
+        config.lconfig_add_settings(&[GPIO]);
+        config.request_lines().unwrap();
+
+        let sim = config.sim();
+        let generator = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(30));
+            let mut sim = sim.lock().unwrap();
+            sim.set_pull(GPIO, Pull::Up).unwrap();
+            thread::sleep(Duration::from_millis(10));
+            sim.set_pull(GPIO, Pull::Down).unwrap();
+            thread::sleep(Duration::from_millis(10));
+            sim.set_pull(GPIO, Pull::Up).unwrap();
+        });
+        // Let all three edges queue up before the stream first polls, so
+        // one notification carries more events than its 2-event buffer.
+        generator.join().unwrap();
+
+        let mut stream = config.request().edge_events_stream(2).unwrap();
+
+        let first = stream.next().await.unwrap().unwrap();
+        assert_eq!(first.event_type, EdgeKind::Rising);
+
+        let second = stream.next().await.unwrap().unwrap();
+        assert_eq!(second.event_type, EdgeKind::Falling);
+
+        let third = stream.next().await.unwrap().unwrap();
+        assert_eq!(third.event_type, EdgeKind::Rising);
+    }
+}
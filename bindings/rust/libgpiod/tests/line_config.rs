@@ -138,4 +138,60 @@ mod line_config {
             Value::InActive
         );
     }
+
+    #[test]
+    fn diff() {
+        let mut unchanged = line::Settings::new().unwrap();
+        unchanged.set_direction(Direction::Input).unwrap();
+
+        let mut dropped = line::Settings::new().unwrap();
+        dropped.set_direction(Direction::Input).unwrap();
+
+        let mut current = line::Config::new().unwrap();
+        current
+            .add_line_settings(&[0], unchanged.settings_clone().unwrap())
+            .unwrap()
+            .add_line_settings(&[3], dropped)
+            .unwrap();
+
+        let mut changed = line::Settings::new().unwrap();
+        changed
+            .set_direction(Direction::Output)
+            .unwrap()
+            .set_output_value(Value::Active)
+            .unwrap();
+
+        let mut new = line::Settings::new().unwrap();
+        new.set_direction(Direction::Input).unwrap();
+
+        let mut target = line::Config::new().unwrap();
+        target
+            .add_line_settings(&[0], unchanged)
+            .unwrap()
+            .add_line_settings(&[1], changed)
+            .unwrap()
+            .add_line_settings(&[2], new)
+            .unwrap();
+
+        let (diff, removed) = target.diff(&current.line_settings().unwrap()).unwrap();
+        let diff_settings = diff.line_settings().unwrap();
+
+        // Offset 0 is identical in both configs, so it is left out.
+        assert!(diff_settings.get(0).is_none());
+
+        // Offset 1 changed, and offset 2 is new - both are included.
+        assert_eq!(
+            diff_settings.get(1).unwrap().prop(SettingKind::Direction).unwrap(),
+            SettingVal::Direction(Direction::Output)
+        );
+        assert_eq!(
+            diff_settings.get(2).unwrap().prop(SettingKind::Direction).unwrap(),
+            SettingVal::Direction(Direction::Input)
+        );
+
+        // Offset 3 is in `current` but absent from `target` - it's not
+        // touched in the returned `Config`, but is flagged as removed.
+        assert!(diff_settings.get(3).is_none());
+        assert_eq!(removed, vec![3]);
+    }
 }
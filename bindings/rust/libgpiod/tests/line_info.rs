@@ -248,14 +248,21 @@ mod line_info {
         }
 
         #[test]
-        #[ignore]
-        fn event_clock_hte() {
+        // gpiosim has no way to advertise HTE support, so requesting lines
+        // with EventClock::HTE is rejected by the kernel with ENODEV. That
+        // makes the success path untestable here, but it does let us pin
+        // down the key invariant: the request fails cleanly with a typed
+        // error rather than silently falling back to another clock.
+        fn event_clock_hte_unsupported() {
+            use libc::ENODEV;
+
             let mut config = TestConfig::new(NGPIO).unwrap();
             config.lconfig_clock(EventClock::HTE);
             config.lconfig_add_settings(&[0]);
-            config.request_lines().unwrap();
-            let info = config.chip().line_info(0).unwrap();
-            assert_eq!(info.event_clock().unwrap(), EventClock::HTE);
+            assert_eq!(
+                config.request_lines().unwrap_err(),
+                ChipError::OperationFailed(OperationType::ChipRequestLines, errno::Errno(ENODEV))
+            );
         }
 
         #[test]
@@ -325,4 +332,87 @@ mod line_info {
             });
         }
     }
+
+    mod snapshot {
+        use libgpiod::line;
+
+        use super::*;
+
+        #[test]
+        fn changes_from_reconfigure() {
+            let mut config = TestConfig::new(NGPIO).unwrap();
+            config.lconfig_bias(Direction::Input, Some(Bias::PullUp));
+            config.lconfig_add_settings(&[0]);
+            config.request_lines().unwrap();
+
+            let before = config.chip().line_info(0).unwrap();
+            let before_snapshot = before.snapshot();
+            assert_eq!(before_snapshot.bias, Some(Bias::PullUp));
+
+            let mut lconfig = line::Config::new().unwrap();
+            let mut settings = line::Settings::new().unwrap();
+            settings
+                .set_direction(Direction::Input)
+                .unwrap()
+                .set_bias(Some(Bias::PullDown))
+                .unwrap();
+            lconfig.add_line_settings(&[0], settings).unwrap();
+            config.request().reconfigure_lines(&lconfig).unwrap();
+
+            let after = config.chip().line_info(0).unwrap();
+            let after_snapshot = after.snapshot();
+
+            // `InfoRef::diff` and `LineInfoSnapshot::changes_from` must agree -
+            // the former is built on top of the latter.
+            let via_info_ref = before.diff(&after);
+            let via_snapshot = after_snapshot.changes_from(&before_snapshot);
+            assert_eq!(via_info_ref, via_snapshot);
+
+            assert_eq!(via_snapshot.bias, Some(Some(Bias::PullDown)));
+            assert_eq!(via_snapshot.direction, None);
+            assert!(!via_snapshot.is_empty());
+
+            // Comparing a snapshot against itself reports no changes.
+            assert!(after_snapshot.changes_from(&after_snapshot).is_empty());
+        }
+
+        #[test]
+        #[cfg(feature = "serde")]
+        fn serde_roundtrip() {
+            let mut config = TestConfig::new(NGPIO).unwrap();
+            config.lconfig_bias(Direction::Input, Some(Bias::PullUp));
+            config.lconfig_debounce(Duration::from_millis(50));
+            config.lconfig_add_settings(&[0]);
+            config.request_lines().unwrap();
+
+            let snapshot = config.chip().line_info(0).unwrap().snapshot();
+
+            let json = serde_json::to_string(&snapshot).unwrap();
+            let restored: line::LineInfoSnapshot = serde_json::from_str(&json).unwrap();
+
+            assert_eq!(restored, snapshot);
+        }
+
+        #[test]
+        fn display() {
+            let sim = Sim::new(Some(NGPIO), None, false).unwrap();
+            sim.set_line_name(0, "foo").unwrap();
+            sim.hog_line(0, "bar", SimDirection::OutputHigh).unwrap();
+            sim.enable().unwrap();
+
+            let chip = Chip::open(&sim.dev_path()).unwrap();
+            let snapshot = chip.line_info(0).unwrap().snapshot();
+
+            assert_eq!(
+                snapshot.to_string(),
+                "line   0:       \"foo\"       \"bar\" output active-high [used]"
+            );
+
+            let unused = chip.line_info(1).unwrap().snapshot();
+            assert_eq!(
+                unused.to_string(),
+                "line   1:     unnamed      unused  input active-high"
+            );
+        }
+    }
 }
@@ -467,8 +467,14 @@ mod line_request {
         }
 
         #[test]
-        #[ignore]
-        fn event_clock_hte() {
+        // gpiosim has no way to advertise HTE support, so reconfiguring a
+        // line to EventClock::HTE is rejected by the kernel with ENODEV.
+        // That makes the success path untestable here, but it does let us
+        // pin down the key invariant: reconfiguring fails cleanly with a
+        // typed error rather than silently keeping the previous clock.
+        fn event_clock_hte_unsupported() {
+            use libc::ENODEV;
+
             let mut config = TestConfig::new(NGPIO).unwrap();
             config.lconfig_add_settings(&[0]);
             config.request_lines().unwrap();
@@ -482,9 +488,17 @@ mod line_request {
             let mut lsettings = line::Settings::new().unwrap();
             lsettings.set_event_clock(EventClock::HTE).unwrap();
             lconfig.add_line_settings(&[0], lsettings).unwrap();
-            request.reconfigure_lines(&lconfig).unwrap();
+            assert_eq!(
+                request.reconfigure_lines(&lconfig).unwrap_err(),
+                ChipError::OperationFailed(
+                    OperationType::LineRequestReconfigLines,
+                    errno::Errno(ENODEV)
+                )
+            );
+
+            // The clock is left unchanged after the failed reconfigure.
             let info = config.chip().line_info(0).unwrap();
-            assert_eq!(info.event_clock().unwrap(), EventClock::HTE);
+            assert_eq!(info.event_clock().unwrap(), EventClock::Monotonic);
         }
 
         #[test]
@@ -5,6 +5,7 @@
 mod common;
 
 mod line_settings {
+    use std::collections::HashSet;
     use std::time::Duration;
 
     use libgpiod::line::{
@@ -165,8 +166,10 @@ mod line_settings {
     }
 
     #[test]
-    #[ignore]
     fn event_clock_hte() {
+        // Only exercises the local Settings object, not a real request, so
+        // unlike the HTE tests in `line_info`/`line_request` this does not
+        // need HTE-capable hardware.
         let mut lsettings = line::Settings::new().unwrap();
         assert_eq!(
             lsettings.prop(SettingKind::EventClock).unwrap(),
@@ -200,4 +203,46 @@ mod line_settings {
             SettingVal::OutputValue(Value::InActive)
         );
     }
+
+    #[test]
+    fn equality() {
+        let mut a = line::Settings::new().unwrap();
+        a.set_prop(&[
+            SettingVal::Direction(Direction::Output),
+            SettingVal::Bias(Some(Bias::PullUp)),
+            SettingVal::Drive(Drive::OpenDrain),
+        ])
+        .unwrap();
+        a.set_output_value(Value::Active).unwrap();
+
+        // A second, independently built `Settings` with the same properties
+        // is a distinct object - not a clone - so this exercises value
+        // equality rather than pointer identity.
+        let mut b = line::Settings::new().unwrap();
+        b.set_prop(&[
+            SettingVal::Direction(Direction::Output),
+            SettingVal::Bias(Some(Bias::PullUp)),
+            SettingVal::Drive(Drive::OpenDrain),
+        ])
+        .unwrap();
+        b.set_output_value(Value::Active).unwrap();
+
+        assert_eq!(a, b);
+
+        let mut c = line::Settings::new().unwrap();
+        c.set_prop(&[
+            SettingVal::Direction(Direction::Output),
+            SettingVal::Bias(Some(Bias::PullDown)),
+            SettingVal::Drive(Drive::OpenDrain),
+        ])
+        .unwrap();
+        c.set_output_value(Value::Active).unwrap();
+
+        assert_ne!(a, c);
+
+        // `Hash` must agree with `PartialEq`: a `HashSet` should dedup `a`
+        // and `b` but keep `c` as a separate entry.
+        let settings: HashSet<_> = [a, b, c].into_iter().collect();
+        assert_eq!(settings.len(), 2);
+    }
 }
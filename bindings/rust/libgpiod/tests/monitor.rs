@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: Apache-2.0 OR BSD-3-Clause
+// SPDX-FileCopyrightText: 2022 Linaro Ltd.
+// SPDX-FileCopyrightText: 2022 Viresh Kumar <viresh.kumar@linaro.org>
+
+mod common;
+
+mod monitor {
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use std::time::Duration;
+
+    use gpiosim_sys::{Pull, Sim};
+    use libgpiod::{
+        line::{Edge, EdgeKind},
+        monitor::{LineWatch, Monitor},
+    };
+
+    const NGPIO: usize = 8;
+
+    #[test]
+    fn wait_records_history() {
+        const GPIO: u32 = 2;
+
+        let sim = Arc::new(Mutex::new(Sim::new(Some(NGPIO), None, true).unwrap()));
+        let dev_path = sim.lock().unwrap().dev_path();
+
+        let mut monitor = Monitor::new(
+            &dev_path,
+            &[LineWatch {
+                offset: GPIO,
+                edge: Edge::Both,
+                debounce_period: Duration::ZERO,
+            }],
+            "monitor-test",
+            4,
+        )
+        .unwrap();
+
+        let sim_clone = sim.clone();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(30));
+            sim_clone.lock().unwrap().set_pull(GPIO, Pull::Up).unwrap();
+        });
+
+        let events = monitor.wait().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].line_offset, GPIO);
+        assert_eq!(events[0].event_type, EdgeKind::Rising);
+
+        let history: Vec<_> = monitor.history(GPIO).collect();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].event_type, EdgeKind::Rising);
+    }
+}
@@ -0,0 +1,102 @@
+// SPDX-License-Identifier: Apache-2.0 OR BSD-3-Clause
+// SPDX-FileCopyrightText: 2022 Linaro Ltd.
+// SPDX-FileCopyrightText: 2022 Viresh Kumar <viresh.kumar@linaro.org>
+
+mod common;
+
+#[cfg(feature = "reactor")]
+mod reactor {
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake, Waker};
+    use std::thread;
+    use std::time::Duration;
+
+    use crate::common::*;
+    use gpiosim_sys::Pull;
+    use libgpiod::line::{Edge, EdgeKind};
+
+    const NGPIO: usize = 8;
+
+    // A minimal executor good enough to drive a single future to
+    // completion, in the spirit of the `reactor` feature itself: no
+    // `tokio`/`async-std` dependency required.
+    fn block_on<F: Future>(fut: F) -> F::Output {
+        struct ThreadWaker(thread::Thread);
+
+        impl Wake for ThreadWaker {
+            fn wake(self: Arc<Self>) {
+                self.0.unpark();
+            }
+        }
+
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        let mut fut: Pin<Box<F>> = Box::pin(fut);
+
+        loop {
+            match fut.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    #[test]
+    fn read_edge_events_async() {
+        const GPIO: u32 = 5;
+
+        let mut config = TestConfig::new(NGPIO).unwrap();
+        config.lconfig_edge(None, Some(Edge::Both));
+        config.lconfig_add_settings(&[GPIO]);
+        config.request_lines().unwrap();
+
+        let sim = config.sim();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(30));
+            sim.lock().unwrap().set_pull(GPIO, Pull::Up).unwrap();
+        });
+
+        let mut buffer = libgpiod::request::Buffer::new(0).unwrap();
+        let mut events = block_on(config.request().read_edge_events_async(&mut buffer)).unwrap();
+
+        let event = events.next().unwrap().unwrap();
+        assert_eq!(event.line_offset(), GPIO);
+        assert_eq!(event.event_type().unwrap(), EdgeKind::Rising);
+    }
+
+    // A burst larger than the buffer's capacity must not be lost: the first
+    // call returns only as many events as fit, and the remainder are still
+    // there to be picked up by a second call.
+    #[test]
+    fn read_edge_events_async_over_capacity() {
+        const GPIO: u32 = 6;
+
+        let mut config = TestConfig::new(NGPIO).unwrap();
+        config.lconfig_edge(None, Some(Edge::Both));
+        config.lconfig_add_settings(&[GPIO]);
+        config.request_lines().unwrap();
+
+        let sim = config.sim();
+        let generator = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(30));
+            let mut sim = sim.lock().unwrap();
+            sim.set_pull(GPIO, Pull::Up).unwrap();
+            thread::sleep(Duration::from_millis(10));
+            sim.set_pull(GPIO, Pull::Down).unwrap();
+            thread::sleep(Duration::from_millis(10));
+            sim.set_pull(GPIO, Pull::Up).unwrap();
+        });
+        // Let all three edges queue up before the first read.
+        generator.join().unwrap();
+
+        let mut buffer = libgpiod::request::Buffer::new(2).unwrap();
+        let events = block_on(config.request().read_edge_events_async(&mut buffer)).unwrap();
+        assert_eq!(events.count(), 2);
+
+        let mut buffer = libgpiod::request::Buffer::new(2).unwrap();
+        let events = block_on(config.request().read_edge_events_async(&mut buffer)).unwrap();
+        assert_eq!(events.count(), 1);
+    }
+}
@@ -0,0 +1,77 @@
+// SPDX-License-Identifier: Apache-2.0 OR BSD-3-Clause
+// SPDX-FileCopyrightText: 2022 Linaro Ltd.
+// SPDX-FileCopyrightText: 2022 Viresh Kumar <viresh.kumar@linaro.org>
+
+mod common;
+
+mod selector {
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use std::time::Duration;
+
+    use gpiosim_sys::{Pull, Sim};
+    use libgpiod::{
+        chip::Chip,
+        line::{self, Direction, Edge, EdgeKind},
+        request,
+        request::Selector,
+    };
+
+    const NGPIO: usize = 8;
+
+    fn request(sim: &Sim, offset: u32) -> request::Request {
+        let mut settings = line::Settings::new().unwrap();
+        settings
+            .set_direction(Direction::Input)
+            .unwrap()
+            .set_edge_detection(Some(Edge::Both))
+            .unwrap();
+
+        let mut lconfig = line::Config::new().unwrap();
+        lconfig.add_line_settings(&[offset], settings).unwrap();
+
+        Chip::open(&sim.dev_path())
+            .unwrap()
+            .request_lines(None, &lconfig)
+            .unwrap()
+    }
+
+    #[test]
+    fn wait_and_poll_next_event() {
+        const GPIO: u32 = 3;
+
+        let sim = Arc::new(Mutex::new(Sim::new(Some(NGPIO), None, true).unwrap()));
+        let mut selector = Selector::new();
+
+        let idle_token = selector.add(request(&sim.lock().unwrap(), 0));
+        let active_token = selector.add(request(&sim.lock().unwrap(), GPIO));
+
+        let sim_clone = sim.clone();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(30));
+            sim_clone.lock().unwrap().set_pull(GPIO, Pull::Up).unwrap();
+        });
+
+        let ready = selector.wait(Some(Duration::from_secs(1))).unwrap();
+        assert_eq!(ready, vec![active_token]);
+        assert_ne!(active_token, idle_token);
+
+        let mut buffer = request::Buffer::new(0).unwrap();
+        let (token, events) = selector
+            .poll_next_event(Some(Duration::from_secs(1)), &mut buffer)
+            .unwrap()
+            .unwrap();
+        assert_eq!(token, active_token);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].line_offset, GPIO);
+        assert_eq!(events[0].event_type, EdgeKind::Rising);
+
+        // No more events queued on any request.
+        assert_eq!(
+            selector
+                .poll_next_event(Some(Duration::from_millis(100)), &mut buffer)
+                .unwrap(),
+            None
+        );
+    }
+}
@@ -0,0 +1,91 @@
+// SPDX-License-Identifier: Apache-2.0 OR BSD-3-Clause
+// SPDX-FileCopyrightText: 2022 Linaro Ltd.
+// SPDX-FileCopyrightText: 2022 Viresh Kumar <viresh.kumar@linaro.org>
+
+mod common;
+
+mod shared_request {
+    use std::thread;
+
+    use gpiosim_sys::Sim;
+    use libgpiod::{
+        chip::Chip,
+        line::{self, Direction, Value},
+        request::SharedRequest,
+    };
+
+    const NGPIO: usize = 8;
+
+    #[test]
+    fn concurrent_value_access() {
+        const OUT: u32 = 0;
+        const IN: u32 = 1;
+
+        let sim = Sim::new(Some(NGPIO), None, true).unwrap();
+
+        let mut out_settings = line::Settings::new().unwrap();
+        out_settings
+            .set_direction(Direction::Output)
+            .unwrap()
+            .set_output_value(Value::InActive)
+            .unwrap();
+
+        let mut in_settings = line::Settings::new().unwrap();
+        in_settings.set_direction(Direction::Input).unwrap();
+
+        let mut lconfig = line::Config::new().unwrap();
+        lconfig
+            .add_line_settings(&[OUT], out_settings)
+            .unwrap()
+            .add_line_settings(&[IN], in_settings)
+            .unwrap();
+
+        let request = Chip::open(&sim.dev_path())
+            .unwrap()
+            .request_lines(None, &lconfig)
+            .unwrap();
+        let shared = SharedRequest::new(request);
+
+        // One thread drives the output line while another reads values
+        // concurrently - both are meant to be safe per `SharedRequest`'s
+        // concurrency guarantees.
+        let writer = {
+            let shared = shared.clone();
+            thread::spawn(move || {
+                for _ in 0..10 {
+                    shared.set_value(OUT, Value::Active).unwrap();
+                    shared.set_value(OUT, Value::InActive).unwrap();
+                }
+            })
+        };
+
+        let reader = {
+            let shared = shared.clone();
+            thread::spawn(move || {
+                for _ in 0..10 {
+                    shared.value(OUT).unwrap();
+                    shared.value(IN).unwrap();
+                }
+            })
+        };
+
+        writer.join().unwrap();
+        reader.join().unwrap();
+
+        shared.set_value(OUT, Value::Active).unwrap();
+        assert_eq!(shared.value(OUT).unwrap(), Value::Active);
+
+        // Reconfiguring is internally serialized and must still succeed.
+        let mut reconfig = line::Config::new().unwrap();
+        let mut settings = line::Settings::new().unwrap();
+        settings
+            .set_direction(Direction::Output)
+            .unwrap()
+            .set_output_value(Value::InActive)
+            .unwrap();
+        reconfig.add_line_settings(&[OUT], settings).unwrap();
+
+        shared.reconfigure_lines(&reconfig).unwrap();
+        assert_eq!(shared.value(OUT).unwrap(), Value::InActive);
+    }
+}
@@ -0,0 +1,103 @@
+// SPDX-License-Identifier: Apache-2.0 OR BSD-3-Clause
+// SPDX-FileCopyrightText: 2022 Linaro Ltd.
+// SPDX-FileCopyrightText: 2022 Viresh Kumar <viresh.kumar@linaro.org>
+
+mod common;
+
+mod sim {
+    use std::time::Duration;
+
+    use crate::common::*;
+    use gpiosim_sys::{Direction as SimDirection, Pull, Sim, SimBuilder, Value as SimValue};
+    use libgpiod::{chip::Chip, line::Edge};
+
+    const NGPIO: usize = 8;
+
+    #[test]
+    fn builder_applies_settings_atomically() {
+        let sim = SimBuilder::new()
+            .set_num_lines(NGPIO)
+            .set_line_name(2, "named")
+            .set_pull(3, Pull::Up)
+            .hog_line(4, "hog", SimDirection::OutputHigh)
+            .build()
+            .unwrap();
+
+        let chip = Chip::open(&sim.dev_path()).unwrap();
+
+        assert_eq!(chip.line_info(2).unwrap().name().unwrap(), "named");
+        assert_eq!(sim.val(3).unwrap(), SimValue::Active);
+        assert_eq!(chip.line_info(4).unwrap().consumer().unwrap(), "hog");
+    }
+
+    #[test]
+    fn drive_sequence_generates_edges() {
+        const GPIO: u32 = 1;
+
+        let mut config = TestConfig::new(NGPIO).unwrap();
+        config.lconfig_edge(None, Some(Edge::Both));
+        config.lconfig_add_settings(&[GPIO]);
+        config.request_lines().unwrap();
+
+        let _driver = Sim::drive_sequence(
+            config.sim(),
+            GPIO,
+            vec![
+                (Pull::Up, Duration::from_millis(20)),
+                (Pull::Down, Duration::from_millis(20)),
+            ],
+        );
+
+        // Waiting does not consume an event, so each one is read off
+        // before waiting for the next.
+        let mut buffer = libgpiod::request::Buffer::new(1).unwrap();
+        assert!(config
+            .request()
+            .wait_edge_events(Some(Duration::from_secs(1)))
+            .unwrap());
+        config.request().read_edge_events(&mut buffer).unwrap();
+        assert!(config
+            .request()
+            .wait_edge_events(Some(Duration::from_secs(1)))
+            .unwrap());
+        config.request().read_edge_events(&mut buffer).unwrap();
+        assert!(!config
+            .request()
+            .wait_edge_events(Some(Duration::from_millis(100)))
+            .unwrap());
+    }
+
+    #[test]
+    fn drive_pattern_repeats_waveform() {
+        const GPIO: u32 = 2;
+        const REPEAT: usize = 3;
+
+        let mut config = TestConfig::new(NGPIO).unwrap();
+        config.lconfig_edge(None, Some(Edge::Both));
+        config.lconfig_add_settings(&[GPIO]);
+        config.request_lines().unwrap();
+
+        let _driver = Sim::drive_pattern(
+            config.sim(),
+            GPIO,
+            vec![
+                (Pull::Up, Duration::from_millis(10)),
+                (Pull::Down, Duration::from_millis(10)),
+            ],
+            REPEAT,
+        );
+
+        let mut buffer = libgpiod::request::Buffer::new(1).unwrap();
+        for _ in 0..REPEAT * 2 {
+            assert!(config
+                .request()
+                .wait_edge_events(Some(Duration::from_secs(1)))
+                .unwrap());
+            config.request().read_edge_events(&mut buffer).unwrap();
+        }
+        assert!(!config
+            .request()
+            .wait_edge_events(Some(Duration::from_millis(100)))
+            .unwrap());
+    }
+}